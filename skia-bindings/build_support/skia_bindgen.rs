@@ -615,6 +615,16 @@ const ENUM_TABLE: &[EnumEntry] = &[
     // m89, SkImageFilters::Dither
     ("Dither", rewrite::k_xxx),
     ("SkScanlineOrder", rewrite::k_xxx_name),
+    // SkImage_RescaleGamma
+    ("RescaleGamma", rewrite::k_xxx),
+    // SkImage_RescaleMode
+    ("RescaleMode", rewrite::k_xxx),
+    // SkJpegEncoder_AlphaOption
+    ("AlphaOption", rewrite::k_xxx),
+    // SkJpegEncoder_Downsample
+    ("Downsample", rewrite::jpeg_downsample),
+    // SkCodecAnimation_DisposalMethod
+    ("DisposalMethod", rewrite::k_xxx),
 ];
 
 pub(crate) mod rewrite {
@@ -653,6 +663,19 @@ pub(crate) mod rewrite {
         capture(name, variant, &format!("k(.*)_{}", name))
     }
 
+    pub fn jpeg_downsample(name: &str, variant: &str) -> String {
+        match variant {
+            "k420" => "BothDirections",
+            "k422" => "Horizontal",
+            "k444" => "No",
+            _ => panic!(
+                "unexpected variant name '{}' of enum type '{}'",
+                variant, name
+            ),
+        }
+        .into()
+    }
+
     pub fn vk(name: &str, variant: &str) -> String {
         let prefix = name.to_shouty_snake_case();
         capture(name, variant, &format!("{}_(.*)", prefix))
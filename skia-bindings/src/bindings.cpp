@@ -59,6 +59,10 @@
 #include "include/core/SkVertices.h"
 // docs/
 #include "include/docs/SkPDFDocument.h"
+// encode/
+#include "include/encode/SkJpegEncoder.h"
+#include "include/encode/SkPngEncoder.h"
+#include "include/encode/SkWebpEncoder.h"
 // effects/
 #include "include/effects/Sk1DPathEffect.h"
 #include "include/effects/Sk2DPathEffect.h"
@@ -154,6 +158,10 @@ extern "C" int C_SkCodec_getFrameCount(SkCodec* self) {
     return self->getFrameCount();
 }
 
+extern "C" bool C_SkCodec_getFrameInfo(const SkCodec* self, int index, SkCodec::FrameInfo* info) {
+    return self->getFrameInfo(index, info);
+}
+
 extern "C" int C_SkCodec_getRepetitionCount(SkCodec* self) {
     return self->getRepetitionCount();
 }
@@ -240,6 +248,32 @@ extern "C" bool C_SkSurface_draw(SkSurface* self, const SkDeferredDisplayList* d
     return self->draw(sp(displayList), xOffset, yOffset);
 }
 
+typedef void (*AsyncReadPixelsCallback)(void* context, const SkImage::AsyncReadResult* result);
+
+namespace {
+    struct AsyncReadPixelsContext {
+        AsyncReadPixelsCallback callback;
+        void* context;
+    };
+
+    void asyncReadPixelsTrampoline(void* context, std::unique_ptr<const SkImage::AsyncReadResult> result) {
+        std::unique_ptr<AsyncReadPixelsContext> ctx(static_cast<AsyncReadPixelsContext*>(context));
+        ctx->callback(ctx->context, result.get());
+    }
+}
+
+extern "C" void C_SkSurface_asyncRescaleAndReadPixels(
+        SkSurface* self,
+        const SkImageInfo* info,
+        const SkIRect* srcRect,
+        SkImage::RescaleGamma rescaleGamma,
+        SkImage::RescaleMode rescaleMode,
+        AsyncReadPixelsCallback callback,
+        void* context) {
+    auto ctx = new AsyncReadPixelsContext { callback, context };
+    self->asyncRescaleAndReadPixels(*info, *srcRect, rescaleGamma, rescaleMode, asyncReadPixelsTrampoline, ctx);
+}
+
 //
 // core/SkSurfaceCharacterization.h
 //
@@ -273,10 +307,18 @@ type) {
     return SkImage::MakeRasterFromCompressed(sp(data), width, height, type).release();
 }
 
+extern "C" SkImage* C_SkImage_MakeRasterCopy(const SkPixmap* pixmap) {
+    return SkImage::MakeRasterCopy(*pixmap).release();
+}
+
 extern "C" SkImage* C_SkImage_MakeRasterData(const SkImageInfo* info, SkData* pixels, size_t rowBytes) {
     return SkImage::MakeRasterData(*info, sp(pixels), rowBytes).release();
 }
 
+extern "C" SkImage* C_SkImage_MakeFromRaster(const SkPixmap* pixmap, SkImage::RasterReleaseProc releaseProc, SkImage::ReleaseContext releaseContext) {
+    return SkImage::MakeFromRaster(*pixmap, releaseProc, releaseContext).release();
+}
+
 extern "C" SkImage* C_SkImage_MakeFromBitmap(const SkBitmap* bitmap) {
     return SkImage::MakeFromBitmap(*bitmap).release();
 }
@@ -346,6 +388,18 @@ extern "C" SkImage* C_SkImage_reinterpretColorSpace(const SkImage* self, SkColor
     return self->reinterpretColorSpace(sp(newColorSpace)).release();
 }
 
+extern "C" int C_SkImage_AsyncReadResult_count(const SkImage::AsyncReadResult* self) {
+    return self->count();
+}
+
+extern "C" const void* C_SkImage_AsyncReadResult_data(const SkImage::AsyncReadResult* self, int i) {
+    return self->data(i);
+}
+
+extern "C" size_t C_SkImage_AsyncReadResult_rowBytes(const SkImage::AsyncReadResult* self, int i) {
+    return self->rowBytes(i);
+}
+
 //
 // core/SkImageEncoder.h
 //
@@ -358,6 +412,49 @@ extern "C" SkData *C_SkEncodeBitmap(const SkBitmap *src, SkEncodedImageFormat fo
     return SkEncodeBitmap(*src, format, quality).release();
 }
 
+//
+// encode/SkJpegEncoder.h
+//
+
+extern "C" bool C_SkJpegEncoder_Encode(
+        SkWStream* stream, const SkPixmap* src,
+        int quality, SkJpegEncoder::Downsample downsample, SkJpegEncoder::AlphaOption alphaOption) {
+    SkJpegEncoder::Options options;
+    options.fQuality = quality;
+    options.fDownsample = downsample;
+    options.fAlphaOption = alphaOption;
+    return SkJpegEncoder::Encode(stream, *src, options);
+}
+
+//
+// encode/SkPngEncoder.h
+//
+
+extern "C" void C_SkPngEncoder_Types(SkPngEncoder::FilterFlag *) {}
+
+extern "C" bool C_SkPngEncoder_Encode(
+        SkWStream* stream, const SkPixmap* src,
+        const SkDataTable* comments, int filterFlags, int zLibLevel) {
+    SkPngEncoder::Options options;
+    options.fFilterFlags = static_cast<SkPngEncoder::FilterFlag>(filterFlags);
+    options.fZLibLevel = zLibLevel;
+    options.fComments = sk_ref_sp(comments);
+    return SkPngEncoder::Encode(stream, *src, options);
+}
+
+//
+// encode/SkWebpEncoder.h
+//
+
+extern "C" bool C_SkWebpEncoder_Encode(
+        SkWStream* stream, const SkPixmap* src,
+        SkWebpEncoder::Compression compression, float quality) {
+    SkWebpEncoder::Options options;
+    options.fCompression = compression;
+    options.fQuality = quality;
+    return SkWebpEncoder::Encode(stream, *src, options);
+}
+
 //
 // core/SkData.h
 //
@@ -378,6 +475,10 @@ extern "C" SkData* C_SkData_MakeWithCopy(const void* data, size_t length) {
     return SkData::MakeWithCopy(data, length).release();
 }
 
+extern "C" void* C_SkData_writable_data(SkData* self) {
+    return self->writable_data();
+}
+
 extern "C" SkData* C_SkData_MakeSubset(const SkData* src, size_t offset, size_t length) {
     return SkData::MakeSubset(src, offset, length).release();
 }
@@ -801,6 +902,10 @@ extern "C" SkColorSpace* C_SkColorSpace_MakeSRGBLinear() {
     return SkColorSpace::MakeSRGBLinear().release();
 }
 
+extern "C" SkColorSpace* C_SkColorSpace_MakeRGB(const skcms_TransferFunction* transferFn, const skcms_Matrix3x3* toXYZ) {
+    return SkColorSpace::MakeRGB(*transferFn, *toXYZ).release();
+}
+
 extern "C" SkColorSpace* C_SkColorSpace_makeLinearGamma(const SkColorSpace* self) {
     return self->makeLinearGamma().release();
 }
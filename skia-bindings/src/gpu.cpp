@@ -1,6 +1,7 @@
 #include "bindings.h"
 #include "include/gpu/GrDirectContext.h"
 #include "include/gpu/GrBackendDrawableInfo.h"
+#include "include/gpu/GrBackendSemaphore.h"
 #include "include/gpu/GrYUVABackendTextures.h"
 #include "include/core/SkCanvas.h"
 #include "include/core/SkDrawable.h"
@@ -174,6 +175,26 @@ extern "C" void C_GrBackendFormat_makeTexture2D(const GrBackendFormat* self, GrB
     *format = self->makeTexture2D();
 }
 
+//
+// gpu/GrBackendSemaphore.h
+//
+
+extern "C" void C_GrBackendSemaphore_Construct(GrBackendSemaphore* uninitialized) {
+    new(uninitialized)GrBackendSemaphore();
+}
+
+extern "C" void C_GrBackendSemaphore_destruct(GrBackendSemaphore* self) {
+    self->~GrBackendSemaphore();
+}
+
+extern "C" bool C_GrBackendSemaphore_isInitialized(const GrBackendSemaphore* self) {
+    return self->isInitialized();
+}
+
+extern "C" GrBackendApi C_GrBackendSemaphore_backend(const GrBackendSemaphore* self) {
+    return self->backend();
+}
+
 //
 // gpu/GrBackendSurfaceMutableState.h
 //
@@ -186,6 +207,14 @@ extern "C" void C_GrBackendSurfaceMutableState_destruct(GrBackendSurfaceMutableS
     self->~GrBackendSurfaceMutableState();
 }
 
+extern "C" bool C_GrBackendSurfaceMutableState_isValid(const GrBackendSurfaceMutableState* self) {
+    return self->isValid();
+}
+
+extern "C" GrBackendApi C_GrBackendSurfaceMutableState_backend(const GrBackendSurfaceMutableState* self) {
+    return self->backend();
+}
+
 //
 // gpu/GrRecordingContext.h
 //
@@ -217,6 +246,13 @@ extern "C" bool C_GrRecordingContext_abandoned(GrRecordingContext* self) {
 // gpu/GrDirectContext.h
 //
 
+extern "C" GrDirectContext* C_GrDirectContext_MakeMock(const GrContextOptions* options) {
+    if (options) {
+        return GrDirectContext::MakeMock(nullptr, *options).release();
+    }
+    return GrDirectContext::MakeMock(nullptr).release();
+}
+
 extern "C" void C_GrDirectContext_flushAndSubmit(GrDirectContext* self) {
     self->flushAndSubmit();
 }
@@ -9,6 +9,7 @@
 #include "include/gpu/vk/GrVkTypes.h"
 #include "include/gpu/vk/GrVkBackendContext.h"
 #include "include/gpu/vk/GrVkExtensions.h"
+#include "include/gpu/vk/GrVkMemoryAllocator.h"
 
 extern "C" void C_GrBackendFormat_ConstructVk(GrBackendFormat* uninitialized, VkFormat format) {
     new(uninitialized)GrBackendFormat(GrBackendFormat::MakeVk(format));
@@ -86,6 +87,24 @@ extern "C" void C_GrVkBackendContext_setMaxAPIVersion(GrVkBackendContext *self,
     self->fMaxAPIVersion = maxAPIVersion;
 }
 
+extern "C" void C_GrVkBackendContext_setDeviceFeatures(GrVkBackendContext *self, const VkPhysicalDeviceFeatures* deviceFeatures) {
+    self->fDeviceFeatures = deviceFeatures;
+    self->fDeviceFeatures2 = nullptr;
+}
+
+extern "C" void C_GrVkBackendContext_setDeviceFeatures2(GrVkBackendContext *self, const VkPhysicalDeviceFeatures2* deviceFeatures2) {
+    self->fDeviceFeatures = nullptr;
+    self->fDeviceFeatures2 = deviceFeatures2;
+}
+
+extern "C" void C_GrVkBackendContext_setMemoryAllocator(GrVkBackendContext *self, GrVkMemoryAllocator* memoryAllocator) {
+    self->fMemoryAllocator = sk_sp<GrVkMemoryAllocator>(memoryAllocator);
+}
+
+extern "C" GrVkMemoryAllocator* C_GrVkBackendContext_memoryAllocator(const GrVkBackendContext *self) {
+    return self->fMemoryAllocator.get();
+}
+
 extern "C" GrDirectContext* C_GrDirectContext_MakeVulkan(
     const GrVkBackendContext* vkBackendContext,
     const GrContextOptions* options) {
@@ -107,6 +126,18 @@ extern "C" bool C_GrVkYcbcrConversionInfo_Equals(const GrVkYcbcrConversionInfo*
     return *lhs == *rhs;
 }
 
+//
+// gpu/GrBackendSemaphore.h
+//
+
+extern "C" void C_GrBackendSemaphore_initVulkan(GrBackendSemaphore* self, VkSemaphore semaphore) {
+    self->initVulkan(semaphore);
+}
+
+extern "C" VkSemaphore C_GrBackendSemaphore_vkSemaphore(const GrBackendSemaphore* self) {
+    return self->vkSemaphore();
+}
+
 //
 // gpu/GrBackendSurfaceMutableState.h
 //
@@ -114,3 +145,11 @@ extern "C" bool C_GrVkYcbcrConversionInfo_Equals(const GrVkYcbcrConversionInfo*
 extern "C" void C_GrBackendSurfaceMutableState_ConstructVK(GrBackendSurfaceMutableState* uninitialized, VkImageLayout layout, uint32_t queueFamilyIndex) {
     new(uninitialized)GrBackendSurfaceMutableState(layout, queueFamilyIndex);
 }
+
+extern "C" VkImageLayout C_GrBackendSurfaceMutableState_getVkImageLayout(const GrBackendSurfaceMutableState* self) {
+    return self->getVkImageLayout();
+}
+
+extern "C" uint32_t C_GrBackendSurfaceMutableState_getQueueFamilyIndex(const GrBackendSurfaceMutableState* self) {
+    return self->getQueueFamilyIndex();
+}
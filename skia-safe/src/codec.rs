@@ -1,4 +1,4 @@
-// TODO: wrap SkAndroidCodec.h, SkCodecAnimation.h
+// TODO: wrap SkAndroidCodec.h
 
 mod _codec;
 pub use _codec::*;
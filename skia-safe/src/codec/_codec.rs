@@ -1,10 +1,10 @@
 use crate::{
-    prelude::*, yuva_pixmap_info::SupportedDataTypes, Data, EncodedImageFormat, EncodedOrigin,
-    IRect, ISize, Image, ImageInfo, Pixmap, YUVAPixmapInfo, YUVAPixmaps,
+    prelude::*, yuva_pixmap_info::SupportedDataTypes, AlphaType, Data, EncodedImageFormat,
+    EncodedOrigin, IRect, ISize, Image, ImageInfo, Pixmap, YUVAPixmapInfo, YUVAPixmaps,
 };
 use ffi::CStr;
-use skia_bindings::{self as sb, SkCodec, SkCodec_Options, SkRefCntBase};
-use std::{ffi, fmt, mem, ptr};
+use skia_bindings::{self as sb, SkCodec, SkCodec_FrameInfo, SkCodec_Options, SkRefCntBase};
+use std::{ffi, fmt, mem, ptr, time::Duration};
 
 pub use sb::SkCodec_Result as Result;
 variant_name!(Result::IncompleteInput, result_naming);
@@ -34,6 +34,41 @@ pub struct Options {
 pub use sb::SkCodec_SkScanlineOrder as ScanlineOrder;
 variant_name!(ScanlineOrder::BottomUp, scanline_order_naming);
 
+pub use sb::SkCodecAnimation_DisposalMethod as DisposalMethod;
+variant_name!(DisposalMethod::RestorePrevious, disposal_method_naming);
+
+/// Information about a single frame of an animated image.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FrameInfo {
+    /// The frame that this frame needs to be blended with, or `None` if it can be decoded
+    /// independently.
+    pub required_frame: Option<usize>,
+    /// The time this frame should be shown.
+    pub duration: Duration,
+    /// Whether the end marker of this frame was seen in the data.
+    pub fully_received: bool,
+    pub alpha_type: AlphaType,
+    /// How this frame should be modified before decoding the next one.
+    pub disposal_method: DisposalMethod,
+    /// The rectangle of the image that is updated by this frame.
+    pub frame_rect: IRect,
+}
+
+impl FrameInfo {
+    fn from_native(native: &SkCodec_FrameInfo) -> Self {
+        const NO_FRAME: i32 = -1;
+        Self {
+            required_frame: (native.fRequiredFrame != NO_FRAME)
+                .if_true_then_some(|| native.fRequiredFrame.try_into().unwrap()),
+            duration: Duration::from_millis(native.fDuration.max(0) as u64),
+            fully_received: native.fFullyReceived,
+            alpha_type: native.fAlphaType,
+            disposal_method: native.fDisposalMethod,
+            frame_rect: IRect::from_native_c(native.fFrameRect),
+        }
+    }
+}
+
 pub type Codec = RCHandle<SkCodec>;
 
 impl NativeBase<SkRefCntBase> for SkCodec {}
@@ -84,6 +119,17 @@ impl Codec {
         EncodedOrigin::from_native_c(unsafe { sb::C_SkCodec_getOrigin(self.native()) })
     }
 
+    /// Returns the dimensions of the image after the [`EncodedOrigin`] is applied, i.e. the
+    /// dimensions the image should be displayed with.
+    pub fn dimensions_after_orientation(&self) -> ISize {
+        let dimensions = self.dimensions();
+        if self.origin().swaps_width_height() {
+            ISize::new(dimensions.height, dimensions.width)
+        } else {
+            dimensions
+        }
+    }
+
     pub fn get_scaled_dimensions(&self, desired_scale: f32) -> ISize {
         ISize::from_native_c(unsafe {
             sb::C_SkCodec_getScaledDimensions(self.native(), desired_scale)
@@ -281,8 +327,18 @@ impl Codec {
             .unwrap()
     }
 
-    // TODO: FrameInfo
-    // TODO: getFrameInfo
+    pub fn get_frame_info(&self, index: usize) -> Option<FrameInfo> {
+        try_construct(|info| unsafe {
+            sb::C_SkCodec_getFrameInfo(self.native(), index.try_into().unwrap(), info)
+        })
+        .map(|info| FrameInfo::from_native(&info))
+    }
+
+    pub fn get_frame_infos(&mut self) -> Vec<FrameInfo> {
+        (0..self.get_frame_count())
+            .filter_map(|index| self.get_frame_info(index))
+            .collect()
+    }
 
     pub fn get_repetition_count(&mut self) -> Option<usize> {
         const REPETITION_COUNT_INFINITE: i32 = -1;
@@ -251,8 +251,8 @@ impl Bitmap {
     /// greater.
     ///
     /// Returns `false` for [ColorType::Unknown].
-    pub fn compute_is_opaque(bm: &Self) -> bool {
-        unsafe { sb::C_SkBitmap_ComputeIsOpaque(bm.native()) }
+    pub fn compute_is_opaque(&self) -> bool {
+        unsafe { sb::C_SkBitmap_ComputeIsOpaque(self.native()) }
     }
 
     /// Returns `IRect { 0, 0, width(), height() }`.
@@ -442,6 +442,27 @@ impl Bitmap {
             .installPixels(info.native(), pixels, row_bytes, None, ptr::null_mut())
     }
 
+    /// Creates a [Bitmap] that uses `pixels` as its pixel memory, for example to draw into an
+    /// externally allocated buffer through [crate::Canvas::from_bitmap()].
+    ///
+    /// `row_bytes` defaults to `info.min_row_bytes()`.
+    ///
+    /// Returns `None` if `info` is invalid, `row_bytes` is too small, or `pixels` does not
+    /// contain enough bytes.
+    pub fn from_pixels<'pixels>(
+        info: &ImageInfo,
+        pixels: &'pixels mut [u8],
+        row_bytes: impl Into<Option<usize>>,
+    ) -> Option<Borrows<'pixels, Bitmap>> {
+        let row_bytes = row_bytes.into().unwrap_or_else(|| info.min_row_bytes());
+        if !info.valid_pixels(row_bytes, pixels) {
+            return None;
+        }
+        let mut bitmap = Bitmap::new();
+        unsafe { bitmap.install_pixels(info, pixels.as_mut_ptr() as _, row_bytes) }
+            .if_true_then_some(move || bitmap.borrows(pixels))
+    }
+
     // TODO: wrap installPixels with SkPixmap&
 
     // TODO: setPixels()?
@@ -725,7 +746,7 @@ impl Bitmap {
 #[cfg(test)]
 mod tests {
     use super::TileMode;
-    use crate::{Bitmap, SamplingOptions};
+    use crate::{AlphaType, Bitmap, Color, SamplingOptions};
 
     #[test]
     fn create_clone_and_drop() {
@@ -767,4 +788,40 @@ mod tests {
         let bm = Bitmap::new();
         let _ = bm.pixel_ref_origin();
     }
+
+    #[test]
+    fn compute_is_opaque_and_promote_alpha_type() {
+        let mut bm = Bitmap::new();
+        bm.alloc_n32_pixels((16, 16), false);
+        bm.erase_color(Color::from_argb(0x80, 0xff, 0, 0));
+        assert!(!bm.compute_is_opaque());
+
+        bm.erase_color(Color::BLUE);
+        assert!(bm.compute_is_opaque());
+        assert_eq!(bm.alpha_type(), AlphaType::Premul);
+
+        assert!(bm.set_alpha_type(AlphaType::Opaque));
+        assert_eq!(bm.alpha_type(), AlphaType::Opaque);
+        assert!(bm.is_opaque());
+    }
+
+    #[test]
+    fn from_pixels_draws_into_the_buffer() {
+        let info = crate::ImageInfo::new_n32_premul((4, 4), None);
+        let mut pixels = vec![0u8; 4 * 4 * 4];
+        {
+            let bitmap = Bitmap::from_pixels(&info, &mut pixels, None).unwrap();
+            assert_eq!(
+                bitmap.peek_pixels().unwrap().get_color((1, 1)),
+                Color::TRANSPARENT
+            );
+            let mut canvas = crate::Canvas::from_bitmap(&bitmap, None);
+            canvas.clear(Color::RED);
+            drop(canvas);
+            assert_eq!(bitmap.peek_pixels().unwrap().get_color((1, 1)), Color::RED);
+        }
+        assert!(pixels.chunks(4).all(|pixel| pixel[3] == 0xff));
+
+        assert!(Bitmap::from_pixels(&info, &mut pixels[..15], None).is_none());
+    }
 }
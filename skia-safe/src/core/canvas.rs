@@ -30,6 +30,7 @@ bitflags! {
         const PRESERVE_LCD_TEXT = sb::SkCanvas_SaveLayerFlagsSet_kPreserveLCDText_SaveLayerFlag as _;
         /// initializes with previous contents
         const INIT_WITH_PREVIOUS = sb::SkCanvas_SaveLayerFlagsSet_kInitWithPrevious_SaveLayerFlag as _;
+        /// uses a half float color type for the layer, for example for HDR backdrops
         const F16_COLOR_TYPE = sb::SkCanvas_SaveLayerFlagsSet_kF16ColorType as _;
     }
 }
@@ -1557,6 +1558,12 @@ impl Canvas {
         self.draw_image_with_sampling_options(image, left_top, SamplingOptions::default(), paint)
     }
 
+    /// Draws the `src` area of `image`, or the whole image if `src` is `None`, scaled and
+    /// translated to fill `dst`.
+    ///
+    /// With [`SrcRectConstraint::Strict`], sampling is restricted to `src`, so pixels next to it
+    /// do not bleed into the result when filtering. This is needed when drawing sprites from an
+    /// atlas, but may be slower. [`SrcRectConstraint::Fast`] allows sampling outside of `src`.
     pub fn draw_image_rect(
         &mut self,
         image: impl AsRef<Image>,
@@ -2344,8 +2351,9 @@ impl AutoCanvasRestore {
 #[cfg(test)]
 mod tests {
     use crate::{
-        canvas::SaveLayerFlags, canvas::SaveLayerRec, AlphaType, Canvas, ClipOp, Color, ColorType,
-        ImageInfo, OwnedCanvas, Rect,
+        canvas::SaveLayerFlags, canvas::SaveLayerRec, paint, AlphaType, BlendMode, Canvas, ClipOp,
+        Color, ColorType, ImageInfo, OwnedCanvas, Paint, Point, PointMode, Rect, SrcRectConstraint,
+        M44,
     };
 
     #[test]
@@ -2417,6 +2425,21 @@ mod tests {
         c.clip_rect(Rect::default(), ClipOp::Difference, true);
     }
 
+    #[test]
+    fn test_local_to_device_keeps_perspective() {
+        let mut surface = crate::Surface::new_raster_n32_premul((100, 100)).unwrap();
+        let canvas = surface.canvas();
+        let m = M44::row_major(&[
+            1.0, 0.0, 0.0, 10.0, 0.0, 1.0, 0.0, 20.0, 0.0, 0.0, 1.0, 0.0, 0.001, 0.0, 0.0, 1.0,
+        ]);
+        canvas.concat_44(&m);
+        assert_eq!(canvas.local_to_device(), m);
+
+        let m33 = canvas.local_to_device_as_3x3();
+        assert!(m33.has_perspective());
+        assert_eq!(m33, m.to_m33());
+    }
+
     /// Regression test for: <https://github.com/rust-skia/rust-skia/issues/427>
     #[test]
     fn test_local_and_device_clip_bounds() {
@@ -2425,4 +2448,258 @@ mod tests {
         let _ = surface.canvas().local_clip_bounds();
         let _ = surface.canvas().local_to_device();
     }
+
+    #[test]
+    fn test_quick_reject() {
+        use crate::{Path, QuickReject};
+
+        let mut surface = crate::Surface::new_raster_n32_premul((100, 100)).unwrap();
+        let canvas = surface.canvas();
+        canvas.clip_rect(Rect::from_xywh(10.0, 10.0, 20.0, 20.0), None, None);
+
+        assert!(canvas.quick_reject(&Rect::from_xywh(60.0, 60.0, 10.0, 10.0)));
+        assert!(!canvas.quick_reject(&Rect::from_xywh(25.0, 25.0, 10.0, 10.0)));
+
+        let far_away = Path::circle((80, 80), 5.0, None);
+        let overlapping = Path::circle((20, 20), 5.0, None);
+        assert!(canvas.quick_reject(&far_away));
+        assert!(!canvas.quick_reject(&overlapping));
+    }
+
+    #[test]
+    fn test_clip_bounds_with_scale() {
+        use crate::{Contains, IRect};
+
+        let mut surface = crate::Surface::new_raster_n32_premul((100, 100)).unwrap();
+        let canvas = surface.canvas();
+        canvas.clip_rect(Rect::from_ltrb(10.0, 10.0, 50.0, 50.0), None, None);
+        canvas.scale((2.0, 2.0));
+
+        assert_eq!(
+            canvas.device_clip_bounds(),
+            Some(IRect::from_ltrb(10, 10, 50, 50))
+        );
+        // local bounds are outset by one device pixel.
+        let local = canvas.local_clip_bounds().unwrap();
+        assert!(local.contains(Rect::from_ltrb(5.0, 5.0, 25.0, 25.0)));
+        assert!(Rect::from_ltrb(4.0, 4.0, 26.0, 26.0).contains(local));
+
+        canvas.clip_rect(Rect::from_xywh(60.0, 60.0, 10.0, 10.0), None, None);
+        assert_eq!(canvas.device_clip_bounds(), None);
+        assert_eq!(canvas.local_clip_bounds(), None);
+    }
+
+    #[test]
+    fn test_is_clip_empty_and_is_clip_rect() {
+        use crate::Path;
+
+        let mut surface = crate::Surface::new_raster_n32_premul((100, 100)).unwrap();
+        let canvas = surface.canvas();
+        assert!(!canvas.is_clip_empty());
+        assert!(canvas.is_clip_rect());
+
+        canvas.save();
+        canvas.clip_rect(Rect::from_xywh(10.0, 10.0, 20.0, 20.0), None, None);
+        assert!(!canvas.is_clip_empty());
+        assert!(canvas.is_clip_rect());
+
+        canvas.clip_path(&Path::circle((20, 20), 5.0, None), None, true);
+        assert!(!canvas.is_clip_empty());
+        assert!(!canvas.is_clip_rect());
+        canvas.restore();
+
+        canvas.clip_rect(Rect::new_empty(), None, None);
+        assert!(canvas.is_clip_empty());
+        assert!(!canvas.is_clip_rect());
+    }
+
+    #[test]
+    fn test_draw_points() {
+        let info = ImageInfo::new((100, 100), ColorType::RGBA8888, AlphaType::Premul, None);
+        let mut bytes = vec![0u8; info.compute_min_byte_size()];
+        let points: Vec<Point> = (0..100)
+            .map(|i| Point::new((i % 10 * 10 + 5) as f32, (i / 10 * 10 + 5) as f32))
+            .collect();
+        {
+            let mut canvas = Canvas::from_raster_direct(&info, &mut bytes, None, None).unwrap();
+            let mut paint = Paint::default();
+            paint
+                .set_color(Color::RED)
+                .set_style(paint::Style::Stroke)
+                .set_stroke_width(2.0);
+            canvas.draw_points(PointMode::Points, &points, &paint);
+        }
+
+        for p in &points {
+            assert_eq!(
+                rgba_100x100(&bytes, p.x as usize, p.y as usize),
+                [0xff, 0x00, 0x00, 0xff]
+            );
+        }
+        // the space between the points stays untouched.
+        assert_eq!(rgba_100x100(&bytes, 0, 0), [0, 0, 0, 0]);
+        assert_eq!(rgba_100x100(&bytes, 10, 10), [0, 0, 0, 0]);
+        assert_eq!(rgba_100x100(&bytes, 99, 99), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_draw_arc_and_oval() {
+        let info = ImageInfo::new((100, 100), ColorType::RGBA8888, AlphaType::Premul, None);
+        let oval = Rect::from_wh(100.0, 100.0);
+        let mut paint = Paint::default();
+        paint.set_color(Color::RED);
+
+        let mut bytes = vec![0u8; info.compute_min_byte_size()];
+        {
+            let mut canvas = Canvas::from_raster_direct(&info, &mut bytes, None, None).unwrap();
+            // a 90 degree pie slice, clockwise from the positive x axis.
+            canvas.draw_arc(oval, 0.0, 90.0, true, &paint);
+        }
+        assert_eq!(rgba_100x100(&bytes, 70, 70), [0xff, 0x00, 0x00, 0xff]);
+        assert_eq!(rgba_100x100(&bytes, 30, 70), [0, 0, 0, 0]);
+        assert_eq!(rgba_100x100(&bytes, 70, 30), [0, 0, 0, 0]);
+        assert_eq!(rgba_100x100(&bytes, 30, 30), [0, 0, 0, 0]);
+
+        let mut bytes = vec![0u8; info.compute_min_byte_size()];
+        {
+            let mut canvas = Canvas::from_raster_direct(&info, &mut bytes, None, None).unwrap();
+            canvas.draw_oval(oval, &paint);
+        }
+        assert_eq!(rgba_100x100(&bytes, 50, 50), [0xff, 0x00, 0x00, 0xff]);
+        assert_eq!(rgba_100x100(&bytes, 30, 70), [0xff, 0x00, 0x00, 0xff]);
+        assert_eq!(rgba_100x100(&bytes, 2, 2), [0, 0, 0, 0]);
+        assert_eq!(rgba_100x100(&bytes, 97, 97), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_save_layer_init_with_previous() {
+        fn draw_layer(flags: SaveLayerFlags) -> [u8; 4] {
+            let info = ImageInfo::new((1, 1), ColorType::RGBA8888, AlphaType::Premul, None);
+            let mut bytes = [0u8; 4];
+            {
+                let mut canvas = Canvas::from_raster_direct(&info, &mut bytes, None, None).unwrap();
+                canvas.clear(Color::RED);
+                // The layer replaces the destination when it is restored.
+                let mut layer_paint = Paint::default();
+                layer_paint.set_blend_mode(BlendMode::Src);
+                canvas.save_layer(&SaveLayerRec::default().paint(&layer_paint).flags(flags));
+                canvas.restore();
+            }
+            bytes
+        }
+
+        assert_eq!(
+            draw_layer(SaveLayerFlags::INIT_WITH_PREVIOUS),
+            [0xff, 0x00, 0x00, 0xff]
+        );
+        assert_eq!(draw_layer(SaveLayerFlags::empty()), [0, 0, 0, 0]);
+        assert_eq!(
+            draw_layer(SaveLayerFlags::INIT_WITH_PREVIOUS | SaveLayerFlags::F16_COLOR_TYPE),
+            [0xff, 0x00, 0x00, 0xff]
+        );
+    }
+
+    #[test]
+    fn test_save_layer_with_backdrop_blur() {
+        fn draw(backdrop: Option<&crate::ImageFilter>) -> Color {
+            let mut surface = crate::Surface::new_raster_n32_premul((20, 10)).unwrap();
+            let canvas = surface.canvas();
+            canvas.clear(Color::BLUE);
+            canvas.draw_rect(
+                Rect::from_wh(10.0, 10.0),
+                Paint::default().set_color(Color::RED),
+            );
+            match backdrop {
+                Some(backdrop) => canvas.save_layer(&SaveLayerRec::default().backdrop(backdrop)),
+                None => canvas.save(),
+            };
+            canvas.restore();
+            surface.peek_pixels().unwrap().get_color((9, 5))
+        }
+
+        assert_eq!(draw(None), Color::RED);
+        let blur = crate::image_filters::blur((3.0, 3.0), None, None, None).unwrap();
+        let blurred = draw(Some(&blur));
+        assert_ne!(blurred, Color::RED);
+        assert!(blurred.r() > 0 && blurred.b() > 0);
+    }
+
+    #[test]
+    fn test_draw_image_at_point() {
+        let mut sprite = crate::Surface::new_raster_n32_premul((4, 4)).unwrap();
+        sprite.canvas().clear(Color::RED);
+        let image = sprite.image_snapshot();
+
+        let info = ImageInfo::new((100, 100), ColorType::RGBA8888, AlphaType::Premul, None);
+        let mut bytes = vec![0u8; info.compute_min_byte_size()];
+        {
+            let mut canvas = Canvas::from_raster_direct(&info, &mut bytes, None, None).unwrap();
+            canvas.draw_image(&image, (10, 10), None);
+            canvas.draw_image_with_sampling_options(
+                &image,
+                (50, 50),
+                crate::SamplingOptions::default(),
+                None,
+            );
+        }
+
+        assert_eq!(rgba_100x100(&bytes, 10, 10), [0xff, 0x00, 0x00, 0xff]);
+        assert_eq!(rgba_100x100(&bytes, 13, 13), [0xff, 0x00, 0x00, 0xff]);
+        assert_eq!(rgba_100x100(&bytes, 9, 9), [0, 0, 0, 0]);
+        assert_eq!(rgba_100x100(&bytes, 14, 14), [0, 0, 0, 0]);
+        assert_eq!(rgba_100x100(&bytes, 50, 50), [0xff, 0x00, 0x00, 0xff]);
+    }
+
+    #[test]
+    fn test_peek_pixels() {
+        let mut surface = crate::Surface::new_raster_n32_premul((16, 16)).unwrap();
+        let canvas = surface.canvas();
+        canvas.clear(Color::WHITE);
+        canvas.draw_rect(
+            Rect::from_xywh(4.0, 4.0, 4.0, 4.0),
+            &Paint::default().set_color(Color::BLUE),
+        );
+
+        let pixmap = canvas.peek_pixels().unwrap();
+        assert_eq!(pixmap.dimensions(), (16, 16).into());
+        assert_eq!(pixmap.get_color((5, 5)), Color::BLUE);
+        assert_eq!(pixmap.get_color((0, 0)), Color::WHITE);
+    }
+
+    #[test]
+    fn test_peek_pixels_of_recording_canvas() {
+        let mut recorder = crate::PictureRecorder::new();
+        let canvas = recorder.begin_recording(Rect::from_wh(16.0, 16.0), None);
+        assert!(canvas.peek_pixels().is_none());
+    }
+
+    #[test]
+    fn test_draw_image_rect_strict_does_not_bleed() {
+        // A 4x2 atlas: the left half is red, the right half is blue.
+        let mut atlas = crate::Surface::new_raster_n32_premul((4, 2)).unwrap();
+        atlas.canvas().clear(Color::BLUE).draw_rect(
+            Rect::from_wh(2.0, 2.0),
+            Paint::default().set_color(Color::RED),
+        );
+        let atlas = atlas.image_snapshot();
+
+        let mut surface = crate::Surface::new_raster_n32_premul((40, 40)).unwrap();
+        surface.canvas().draw_image_rect_with_sampling_options(
+            &atlas,
+            Some((&Rect::from_wh(2.0, 2.0), SrcRectConstraint::Strict)),
+            Rect::from_wh(40.0, 40.0),
+            crate::SamplingOptions::new(crate::FilterMode::Linear, crate::MipmapMode::None),
+            &Paint::default(),
+        );
+
+        let pixmap = surface.peek_pixels().unwrap();
+        for y in 0..40 {
+            assert_eq!(pixmap.get_color((39, y)), Color::RED);
+        }
+    }
+
+    fn rgba_100x100(bytes: &[u8], x: usize, y: usize) -> &[u8] {
+        let offset = (y * 100 + x) * 4;
+        &bytes[offset..offset + 4]
+    }
 }
@@ -348,6 +348,35 @@ impl Color4f {
     pub fn to_opaque(self) -> Self {
         Self { a: 1.0, ..self }
     }
+
+    /// Converts the sRGB encoded color components to linear sRGB. Alpha is not affected.
+    pub fn to_linear_srgb(self) -> Self {
+        fn linear(c: f32) -> f32 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        Self::new(linear(self.r), linear(self.g), linear(self.b), self.a)
+    }
+
+    /// Converts linear sRGB color components to sRGB encoded ones. Alpha is not affected.
+    pub fn from_linear_srgb(linear: Color4f) -> Self {
+        fn encoded(c: f32) -> f32 {
+            if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+        Self::new(
+            encoded(linear.r),
+            encoded(linear.g),
+            encoded(linear.b),
+            linear.a,
+        )
+    }
 }
 
 pub mod colors {
@@ -397,4 +426,19 @@ mod tests {
         fn passed_as_ref(_c: impl AsRef<Color4f>) {}
         passed_as_ref(colors::BLACK);
     }
+
+    #[test]
+    pub fn color4f_srgb_linear_conversion() {
+        let grey = Color4f::new(0.5, 0.5, 0.5, 0.5);
+        let linear = grey.to_linear_srgb();
+        assert!((linear.r - 0.214).abs() < 0.001);
+        assert!((linear.g - 0.214).abs() < 0.001);
+        assert!((linear.b - 0.214).abs() < 0.001);
+        assert!((linear.a - 0.5).abs() < f32::EPSILON);
+
+        let encoded = Color4f::from_linear_srgb(linear);
+        for (e, g) in encoded.as_array().iter().zip(grey.as_array()) {
+            assert!((e - g).abs() < 0.0001);
+        }
+    }
 }
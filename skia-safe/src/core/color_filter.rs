@@ -174,4 +174,32 @@ mod tests {
             None,
         );
     }
+
+    #[test]
+    fn compose_scale_filters() {
+        // A row major color matrix that scales red and green.
+        fn scale(r: f32, g: f32) -> [f32; 20] {
+            let mut m = [0.0; 20];
+            m[0] = r;
+            m[6] = g;
+            m[12] = 1.0;
+            m[18] = 1.0;
+            m
+        }
+
+        let outer = color_filters::matrix_row_major(&scale(0.5, 1.0));
+        let inner = color_filters::matrix_row_major(&scale(0.5, 0.25));
+        assert_eq!(inner.to_a_color_matrix(), Some(scale(0.5, 0.25)));
+
+        let composed = outer.composed(inner).unwrap();
+        let c = composed.filter_color4f(
+            Color4f::new(1.0, 1.0, 1.0, 1.0),
+            &ColorSpace::new_srgb(),
+            None,
+        );
+        assert!((c.r - 0.25).abs() < 1e-4);
+        assert!((c.g - 0.25).abs() < 1e-4);
+        assert!((c.b - 1.0).abs() < 1e-4);
+        assert!((c.a - 1.0).abs() < 1e-4);
+    }
 }
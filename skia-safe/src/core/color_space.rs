@@ -1,6 +1,8 @@
 use super::Data;
 use crate::prelude::*;
-use skia_bindings::{self as sb, SkColorSpace, SkColorSpacePrimaries};
+use skia_bindings::{
+    self as sb, skcms_Matrix3x3, skcms_TransferFunction, SkColorSpace, SkColorSpacePrimaries,
+};
 use std::fmt;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -22,7 +24,8 @@ native_transmutable!(
     color_space_primaries_layout
 );
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Default, Debug)]
+#[repr(C)]
 pub struct ColorSpaceTransferFn {
     pub g: f32,
     pub a: f32,
@@ -33,6 +36,21 @@ pub struct ColorSpaceTransferFn {
     pub f: f32,
 }
 
+native_transmutable!(
+    skcms_TransferFunction,
+    ColorSpaceTransferFn,
+    color_space_transfer_fn_layout
+);
+
+/// A gamut, represented by its row major matrix that converts to the XYZ D50 color space.
+#[derive(Clone, PartialEq, Default, Debug)]
+#[repr(C)]
+pub struct ColorSpaceXYZ {
+    pub vals: [[f32; 3]; 3],
+}
+
+native_transmutable!(skcms_Matrix3x3, ColorSpaceXYZ, color_space_xyz_layout);
+
 // TODO: Make the binding generator provide all these constants.
 pub mod named_transfer_fn {
     use crate::ColorSpaceTransferFn;
@@ -99,6 +117,48 @@ pub mod named_transfer_fn {
     };
 }
 
+// TODO: Make the binding generator provide all these constants.
+pub mod named_gamut {
+    use crate::ColorSpaceXYZ;
+
+    #[allow(clippy::excessive_precision)]
+    pub const SRGB: ColorSpaceXYZ = ColorSpaceXYZ {
+        vals: [
+            [0.436_065_674, 0.385_147_095, 0.143_066_406],
+            [0.222_488_403, 0.716_873_169, 0.060_607_910],
+            [0.013_916_016, 0.097_076_416, 0.714_096_069],
+        ],
+    };
+
+    pub const ADOBE_RGB: ColorSpaceXYZ = ColorSpaceXYZ {
+        vals: [
+            [0.609_74, 0.205_28, 0.149_19],
+            [0.311_11, 0.625_67, 0.063_22],
+            [0.019_47, 0.060_87, 0.744_57],
+        ],
+    };
+
+    pub const DISPLAY_P3: ColorSpaceXYZ = ColorSpaceXYZ {
+        vals: [
+            [0.515_102, 0.291_965, 0.157_153],
+            [0.241_182, 0.692_236, 0.066_581_9],
+            [-0.001_049_41, 0.041_881_8, 0.784_378],
+        ],
+    };
+
+    pub const REC2020: ColorSpaceXYZ = ColorSpaceXYZ {
+        vals: [
+            [0.673_459, 0.165_661, 0.125_100],
+            [0.279_033, 0.675_338, 0.045_628_8],
+            [-0.001_931_39, 0.029_979_4, 0.797_162],
+        ],
+    };
+
+    pub const XYZ: ColorSpaceXYZ = ColorSpaceXYZ {
+        vals: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+}
+
 pub type ColorSpace = RCHandle<SkColorSpace>;
 unsafe_send_sync!(ColorSpace);
 
@@ -137,6 +197,37 @@ impl ColorSpace {
         ColorSpace::from_ptr(unsafe { sb::C_SkColorSpace_MakeSRGBLinear() }).unwrap()
     }
 
+    /// Creates a color space from a transfer function and a gamut, for example
+    /// [`named_transfer_fn::PQ`] and [`named_gamut::REC2020`].
+    ///
+    /// Returns `None` if the transfer function or the gamut is invalid.
+    pub fn new_rgb(
+        transfer_fn: &ColorSpaceTransferFn,
+        to_xyz: &ColorSpaceXYZ,
+    ) -> Option<ColorSpace> {
+        ColorSpace::from_ptr(unsafe {
+            sb::C_SkColorSpace_MakeRGB(transfer_fn.native(), to_xyz.native())
+        })
+    }
+
+    /// Returns the transfer function of the color space if it can be represented as a
+    /// [`ColorSpaceTransferFn`], for example `None` is returned for PQ and HLG.
+    pub fn is_numerical_transfer_fn(&self) -> Option<ColorSpaceTransferFn> {
+        let mut transfer_fn = ColorSpaceTransferFn::default();
+        unsafe {
+            self.native()
+                .isNumericalTransferFn(transfer_fn.native_mut())
+        }
+        .if_true_some(transfer_fn)
+    }
+
+    /// Returns the matrix that converts the color space's gamut to XYZ D50, if the gamut can be
+    /// represented as one.
+    pub fn to_xyzd50(&self) -> Option<ColorSpaceXYZ> {
+        let mut to_xyzd50 = ColorSpaceXYZ::default();
+        unsafe { self.native().toXYZD50(to_xyzd50.native_mut()) }.if_true_some(to_xyzd50)
+    }
+
     pub fn to_xyzd50_hash(&self) -> XYZD50Hash {
         XYZD50Hash(self.native().fToXYZD50Hash)
     }
@@ -213,3 +304,37 @@ pub fn serialize_and_deserialize() {
 
     assert!(original == deserialized);
 }
+
+#[test]
+fn new_rgb_from_named_constants() {
+    let srgb = ColorSpace::new_rgb(&named_transfer_fn::SRGB, &named_gamut::SRGB).unwrap();
+    assert!(srgb.is_srgb());
+
+    let hdr = ColorSpace::new_rgb(&named_transfer_fn::PQ, &named_gamut::REC2020).unwrap();
+    assert!(!hdr.is_srgb());
+
+    let hlg = ColorSpace::new_rgb(&named_transfer_fn::HLG, &named_gamut::DISPLAY_P3).unwrap();
+    assert!(!hlg.is_srgb());
+}
+
+#[test]
+fn srgb_transfer_fn_and_gamut() {
+    let srgb = ColorSpace::new_srgb();
+    let transfer_fn = srgb.is_numerical_transfer_fn().unwrap();
+    assert!((transfer_fn.g - 2.4).abs() < 1e-4);
+    assert!((transfer_fn.a - 1.0 / 1.055).abs() < 1e-4);
+    assert!((transfer_fn.b - 0.055 / 1.055).abs() < 1e-4);
+    assert!((transfer_fn.c - 1.0 / 12.92).abs() < 1e-4);
+    assert!((transfer_fn.d - 0.04045).abs() < 1e-4);
+    assert_eq!(srgb.to_xyzd50().unwrap(), named_gamut::SRGB);
+
+    let linear = ColorSpace::new_srgb_linear();
+    assert_eq!(
+        linear.is_numerical_transfer_fn().unwrap(),
+        named_transfer_fn::LINEAR
+    );
+
+    let hdr = ColorSpace::new_rgb(&named_transfer_fn::PQ, &named_gamut::REC2020).unwrap();
+    assert!(hdr.is_numerical_transfer_fn().is_none());
+    assert_eq!(hdr.to_xyzd50().unwrap(), named_gamut::REC2020);
+}
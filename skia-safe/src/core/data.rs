@@ -57,8 +57,15 @@ impl Data {
         unsafe { safer::from_raw_parts(self.native().fPtr as _, self.size()) }
     }
 
-    // TODO:
-    // pub unsafe fn writable_data(&mut self) -> &mut [u8]
+    /// Returns the bytes of the Data for writing.
+    ///
+    /// # Safety
+    /// The Data must not be shared, and must own its bytes, which is not the case if it was
+    /// created with [`Data::new_bytes()`].
+    pub unsafe fn writable_data(&mut self) -> &mut [u8] {
+        let size = self.size();
+        safer::from_raw_parts_mut(sb::C_SkData_writable_data(self.native_mut()) as _, size)
+    }
 
     pub fn copy_range(&self, offset: usize, buffer: &mut [u8]) -> &Self {
         buffer.copy_from_slice(&self.as_bytes()[offset..offset + buffer.len()]);
@@ -84,6 +91,18 @@ impl Data {
         Data::from_ptr(sb::C_SkData_MakeUninitialized(length)).unwrap()
     }
 
+    /// Constructs Data of `length` bytes and lets `fill` write its contents in place.
+    ///
+    /// This avoids filling a separate buffer that needs to be copied into the Data afterwards.
+    /// The bytes passed to `fill` are zero initialized.
+    pub fn new_with_fill(length: usize, fill: impl FnOnce(&mut [u8])) -> Data {
+        let mut data = unsafe { Self::new_uninitialized(length) };
+        let bytes = unsafe { data.writable_data() };
+        bytes.fill(0);
+        fill(bytes);
+        data
+    }
+
     // TODO: use Range as stand in for offset / length?
     pub fn new_subset(data: &Data, offset: usize, length: usize) -> Data {
         Data::from_ptr(unsafe { sb::C_SkData_MakeSubset(data.native(), offset, length) }).unwrap()
@@ -126,3 +145,19 @@ fn data_supports_equals() {
     let d2 = Data::new_copy(x);
     assert!(d1 == d2)
 }
+
+#[test]
+fn data_can_be_filled_in_place() {
+    let data = Data::new_with_fill(1024, |bytes| {
+        assert_eq!(bytes.len(), 1024);
+        assert!(bytes.iter().all(|b| *b == 0));
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+    });
+    assert_eq!(data.size(), 1024);
+    assert!(data.iter().enumerate().all(|(i, b)| *b == i as u8));
+
+    let empty = Data::new_with_fill(0, |bytes| assert!(bytes.is_empty()));
+    assert!(empty.is_empty());
+}
@@ -298,6 +298,31 @@ impl Font {
         (width, bounds)
     }
 
+    /// Returns the advance width of the UTF-8 encoded `str`, without computing its bounds.
+    pub fn measure_str_width(&self, str: impl AsRef<str>, paint: Option<&Paint>) -> scalar {
+        let bytes = str.as_ref().as_bytes();
+        self.measure_text_width(bytes, TextEncoding::UTF8, paint)
+    }
+
+    /// Returns the advance width of `text`, which is interpreted according to `encoding`, without
+    /// computing its bounds.
+    pub fn measure_text_width(
+        &self,
+        text: &[u8],
+        encoding: TextEncoding,
+        paint: Option<&Paint>,
+    ) -> scalar {
+        unsafe {
+            self.native().measureText(
+                text.as_ptr() as _,
+                text.len(),
+                encoding.into_native(),
+                ptr::null_mut(),
+                paint.native_ptr_or_null(),
+            )
+        }
+    }
+
     pub fn unichar_to_glyph(&self, uni: Unichar) -> GlyphId {
         unsafe { self.native().unicharToGlyph(uni) }
     }
@@ -317,6 +342,13 @@ impl Font {
         self.get_widths_bounds(glyphs, Some(widths), None, None)
     }
 
+    // convenience function
+    pub fn get_widths_vec(&self, glyphs: &[GlyphId]) -> Vec<scalar> {
+        let mut widths = vec![0.0; glyphs.len()];
+        self.get_widths(glyphs, &mut widths);
+        widths
+    }
+
     pub fn get_widths_bounds(
         &self,
         glyphs: &[GlyphId],
@@ -444,3 +476,92 @@ fn test_flags() {
     font.set_embolden(false);
     assert!(!font.is_embolden());
 }
+
+#[test]
+fn test_with_size_and_scale_x() {
+    let font = Font::new(Typeface::default(), 24.0);
+    let (advance, _) = font.measure_str("Hello", None);
+    if advance == 0.0 {
+        // no default typeface available.
+        return;
+    }
+
+    let small = font.with_size(12.0).unwrap();
+    assert_eq!(small.size(), 12.0);
+    assert!(Typeface::equal(
+        small.typeface_or_default(),
+        font.typeface_or_default()
+    ));
+    let (small_advance, _) = small.measure_str("Hello", None);
+    assert!(small_advance < advance);
+    assert!(font.with_size(-1.0).is_none());
+
+    let mut condensed = font.with_size(24.0).unwrap();
+    condensed.set_scale_x(0.5).set_skew_x(-0.25);
+    assert_eq!(condensed.scale_x(), 0.5);
+    assert_eq!(condensed.skew_x(), -0.25);
+    let (condensed_advance, _) = condensed.measure_str("Hello", None);
+    assert!(condensed_advance < advance);
+    assert_eq!(
+        Font::from_typeface_with_params(font.typeface_or_default(), 24.0, 0.5, -0.25)
+            .measure_str("Hello", None)
+            .0,
+        condensed_advance
+    );
+}
+
+#[test]
+fn test_get_widths() {
+    let font = Font::new(Typeface::default(), 12.0);
+    let glyphs = font.str_to_glyphs_vec("Hello, World");
+    let widths = font.get_widths_vec(&glyphs);
+    assert_eq!(widths.len(), glyphs.len());
+
+    let mut widths_and_bounds = vec![0.0; glyphs.len()];
+    let mut bounds = vec![Rect::default(); glyphs.len()];
+    font.get_widths_bounds(
+        &glyphs,
+        Some(&mut widths_and_bounds),
+        Some(&mut bounds),
+        None,
+    );
+    assert_eq!(widths, widths_and_bounds);
+}
+
+#[test]
+fn test_measure_str_width() {
+    let font = Font::new(Typeface::default(), 12.0);
+    let text = "Hello, World";
+    let width = font.measure_str_width(text, None);
+    assert_eq!(width, font.measure_str(text, None).0);
+
+    let widths = font.get_widths_vec(&font.str_to_glyphs_vec(text));
+    let sum: scalar = widths.iter().sum();
+    assert!((width - sum).abs() < 0.01);
+}
+
+#[test]
+fn test_text_to_glyphs_matches_typeface() {
+    let font = Font::new(Typeface::default(), 12.0);
+    let text = "affine flow";
+
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let utf16_bytes =
+        unsafe { std::slice::from_raw_parts(utf16.as_ptr() as *const u8, utf16.len() * 2) };
+    assert_eq!(font.count_str(text), text.chars().count());
+    assert_eq!(
+        font.count_text(utf16_bytes, TextEncoding::UTF16),
+        text.chars().count()
+    );
+
+    let glyphs = font.str_to_glyphs_vec(text);
+    let unichars: Vec<Unichar> = text.chars().map(|c| c as Unichar).collect();
+    let mut typeface_glyphs = vec![GlyphId::default(); unichars.len()];
+    font.typeface_or_default()
+        .unichars_to_glyphs(&unichars, &mut typeface_glyphs);
+    assert_eq!(glyphs, typeface_glyphs);
+    assert_eq!(
+        font.text_to_glyphs_vec(utf16_bytes, TextEncoding::UTF16),
+        glyphs
+    );
+}
@@ -210,7 +210,7 @@ impl FontMgr {
 
 #[cfg(test)]
 mod tests {
-    use crate::FontMgr;
+    use crate::{font_style::Weight, FontMgr, FontStyle};
 
     #[test]
     #[serial_test::serial]
@@ -235,4 +235,24 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn match_family_style_resolves_bold_face() {
+        let font_mgr = FontMgr::default();
+        // Find a family that provides a bold face.
+        let family = font_mgr.family_names().find(|family| {
+            let mut style_set = font_mgr.match_family(family);
+            (0..style_set.count()).any(|i| *style_set.style(i).0.weight() >= *Weight::BOLD)
+        });
+        let family = match family {
+            Some(family) => family,
+            None => return,
+        };
+
+        let typeface = font_mgr
+            .match_family_style(&family, FontStyle::bold())
+            .unwrap();
+        assert!(typeface.is_bold());
+    }
 }
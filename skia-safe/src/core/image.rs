@@ -1,3 +1,4 @@
+use super::pixmap::hashing;
 #[cfg(feature = "gpu")]
 use crate::gpu;
 use crate::{
@@ -5,8 +6,8 @@ use crate::{
     ISize, ImageFilter, ImageGenerator, ImageInfo, Matrix, Paint, Picture, Pixmap, SamplingOptions,
     Shader, TileMode,
 };
-use skia_bindings::{self as sb, SkImage, SkRefCntBase};
-use std::{fmt, mem, ptr};
+use skia_bindings::{self as sb, SkImage, SkImage_AsyncReadResult, SkRefCntBase};
+use std::{convert::TryInto, ffi::c_void, fmt, mem, ptr};
 
 pub use super::CubicResampler;
 
@@ -19,6 +20,54 @@ variant_name!(CachingHint::Allow, caching_hint_naming);
 pub use skia_bindings::SkImage_CompressionType as CompressionType;
 variant_name!(CompressionType::BC1_RGBA8_UNORM, compression_type_naming);
 
+pub use skia_bindings::SkImage_RescaleGamma as RescaleGamma;
+variant_name!(RescaleGamma::Linear, rescale_gamma_naming);
+
+pub use skia_bindings::SkImage_RescaleMode as RescaleMode;
+variant_name!(RescaleMode::RepeatedCubic, rescale_mode_naming);
+
+/// The result of an asynchronous pixel read. For RGBA reads, there is exactly one plane.
+pub type AsyncReadResult = Handle<SkImage_AsyncReadResult>;
+
+impl NativeDrop for SkImage_AsyncReadResult {
+    fn drop(&mut self) {
+        panic!("native type SkImage::AsyncReadResult can't be owned by Rust");
+    }
+}
+
+impl fmt::Debug for AsyncReadResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncReadResult")
+            .field("count", &self.count())
+            .finish()
+    }
+}
+
+impl AsyncReadResult {
+    /// The number of planes.
+    pub fn count(&self) -> usize {
+        unsafe { sb::C_SkImage_AsyncReadResult_count(self.native()) }
+            .try_into()
+            .unwrap()
+    }
+
+    /// A pointer to the pixels of plane `i`. Panics if `i` is not less than [`Self::count()`].
+    ///
+    /// # Safety
+    /// The result does not know the height of its planes, so the pointer is only valid for
+    /// `row_bytes(i) * height` bytes, where `height` is the height of the plane that was
+    /// requested. The pixels are only valid while the [`AsyncReadResult`] is borrowed.
+    pub unsafe fn data(&self, i: usize) -> *const c_void {
+        assert!(i < self.count());
+        sb::C_SkImage_AsyncReadResult_data(self.native(), i.try_into().unwrap())
+    }
+
+    pub fn row_bytes(&self, i: usize) -> usize {
+        assert!(i < self.count());
+        unsafe { sb::C_SkImage_AsyncReadResult_rowBytes(self.native(), i.try_into().unwrap()) }
+    }
+}
+
 pub type Image = RCHandle<SkImage>;
 unsafe_send_sync!(Image);
 
@@ -47,7 +96,10 @@ impl fmt::Debug for Image {
 }
 
 impl Image {
-    // TODO: MakeRasterCopy()
+    /// Creates a raster [`Image`] that owns a copy of the pixels of `pixmap`.
+    pub fn from_pixmap_copy(pixmap: &Pixmap) -> Option<Image> {
+        Image::from_ptr(unsafe { sb::C_SkImage_MakeRasterCopy(pixmap.native()) })
+    }
 
     pub fn from_raster_data(
         info: &ImageInfo,
@@ -59,8 +111,36 @@ impl Image {
         })
     }
 
-    // TODO: MakeFromRaster()
+    /// Creates a raster [`Image`] that shares the pixels of `pixmap` without copying them.
+    ///
+    /// `release` is called as soon as Skia does not need the pixels anymore. This happens when the
+    /// last reference to the [`Image`] is dropped, or immediately if the [`Image`] could not be
+    /// created.
+    ///
+    /// # Safety
+    ///
+    /// The pixels `pixmap` refers to must stay valid and unchanged until `release` is called.
+    pub unsafe fn from_raster<F>(pixmap: &Pixmap, release: F) -> Option<Image>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let context = Box::into_raw(Box::new(release));
+        let image = Image::from_ptr(sb::C_SkImage_MakeFromRaster(
+            pixmap.native(),
+            Some(raster_release_proc::<F>),
+            context as _,
+        ));
+        if image.is_none() {
+            raster_release_proc::<F>(ptr::null(), context as _);
+        }
+        image
+    }
 
+    /// Creates a raster [`Image`] from `bitmap`.
+    ///
+    /// If `bitmap` is immutable (see [`Bitmap::set_immutable()`]), the image shares the bitmap's
+    /// pixels without copying them. Otherwise the pixels are copied, so later changes to the
+    /// bitmap do not affect the image.
     pub fn from_bitmap(bitmap: &Bitmap) -> Option<Image> {
         Image::from_ptr(unsafe { sb::C_SkImage_MakeFromBitmap(bitmap.native()) })
     }
@@ -321,6 +401,11 @@ impl Image {
         self.alpha_type().is_opaque()
     }
 
+    /// Creates a [`Shader`] that draws this image, tiled horizontally and vertically according
+    /// to `tile_modes`. `None` clamps in both directions.
+    ///
+    /// Use [`TileMode::Decal`] to draw transparent outside of the image bounds, for example for
+    /// non-repeating sprites.
     pub fn to_shader<'a>(
         &self,
         tile_modes: impl Into<Option<(TileMode, TileMode)>>,
@@ -369,10 +454,11 @@ impl Image {
         context: &mut gpu::DirectContext,
         flush_info: &gpu::FlushInfo,
     ) -> gpu::SemaphoresSubmitted {
-        unsafe {
-            self.native_mut()
-                .flush(context.native_mut(), flush_info.native())
-        }
+        let is_texture_backed = self.is_texture_backed();
+        let image = self.native_mut();
+        flush_info.flush_with(is_texture_backed, |info| unsafe {
+            image.flush(context.native_mut(), info)
+        })
     }
 
     // TODO: m86: implement new flush() variant that is based on flush_with_info() as soon the old
@@ -488,6 +574,24 @@ impl Image {
         }
     }
 
+    /// Computes a hash of the pixels that is stable across platforms, for example to compare
+    /// rendered output in tests. This is a rust-skia specific function.
+    ///
+    /// See [`Pixmap::compute_hash()`]. Returns `None` if the pixels can not be read without a
+    /// GPU context, which is the case for texture backed images.
+    pub fn compute_hash(&self) -> Option<u64> {
+        let info = hashing::canonical_info(self.dimensions());
+        let mut pixels = vec![0u8; info.compute_min_byte_size()];
+        self.read_pixels(
+            &info,
+            &mut pixels,
+            info.min_row_bytes(),
+            (0, 0),
+            CachingHint::Disallow,
+        )
+        .if_true_then_some(|| hashing::hash(&info, &pixels))
+    }
+
     #[cfg(feature = "gpu")]
     #[allow(clippy::missing_safety_doc)]
     pub unsafe fn read_pixels_to_pixmap(
@@ -503,7 +607,6 @@ impl Image {
     }
 
     // TODO:
-    // AsyncReadResult, ReadPixelsContext, ReadPixelsCallback, RescaleGamma,
     // asyncRescaleAndReadPixels, asyncRescaleAndReadPixelsYUV420
 
     #[must_use]
@@ -526,14 +629,53 @@ impl Image {
         self.encode_to_data_with_quality(image_format, 100)
     }
 
+    /// Encodes the image's pixels using the specified format and returns the encoded data.
+    ///
+    /// `quality` is a value from 0 to 100 and is clamped to that range. It is a suggestion to
+    /// lossy encoders like JPEG and WEBP, lossless formats ignore it.
     pub fn encode_to_data_with_quality(
         &self,
         image_format: EncodedImageFormat,
         quality: i32,
     ) -> Option<Data> {
+        let quality = quality.clamp(0, 100);
         Data::from_ptr(unsafe { sb::C_SkImage_encodeToData(self.native(), image_format, quality) })
     }
 
+    /// Encodes the image's pixels as a PNG.
+    ///
+    /// Returns `None` if the pixels can not be read or encoded, or the options are invalid. See
+    /// [`crate::encode::png_encoder::encode()`].
+    pub fn encode_png(&self, options: &crate::encode::png_encoder::Options) -> Option<Data> {
+        self.with_raster_pixmap(|pixmap| crate::encode::png_encoder::encode(pixmap, options))
+    }
+
+    /// Encodes the image's pixels as a WEBP. `quality` is in the range `0.0..=100.0` and ignored if
+    /// `lossless` is set.
+    ///
+    /// Returns `None` if the pixels can not be read or encoded, or the quality is out of range.
+    /// See [`crate::encode::webp_encoder::encode()`] for more control.
+    pub fn encode_webp(&self, lossless: bool, quality: f32) -> Option<Data> {
+        use crate::encode::webp_encoder;
+        let options = if lossless {
+            webp_encoder::Options::lossless()
+        } else {
+            webp_encoder::Options::lossy(quality)
+        };
+        self.with_raster_pixmap(|pixmap| webp_encoder::encode(pixmap, &options))
+    }
+
+    /// Calls `f` with the pixels of the image, reads them into a raster image first if they can
+    /// not be accessed directly.
+    fn with_raster_pixmap<R>(&self, f: impl FnOnce(&Pixmap) -> Option<R>) -> Option<R> {
+        if let Some(pixmap) = self.peek_pixels() {
+            return f(&*pixmap);
+        }
+        let raster = self.new_raster_image()?;
+        let pixmap = raster.peek_pixels()?;
+        f(&*pixmap)
+    }
+
     pub fn encoded_data(&self) -> Option<Data> {
         Data::from_ptr(unsafe { sb::C_SkImage_refEncodedData(self.native()) })
     }
@@ -694,3 +836,155 @@ impl Image {
         })
     }
 }
+
+unsafe extern "C" fn raster_release_proc<F>(_pixels: *const c_void, context: *mut c_void)
+where
+    F: FnOnce(),
+{
+    let release = Box::from_raw(context as *mut F);
+    release()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AlphaType, Bitmap, Color, ColorType, EncodedImageFormat, Image, ImageInfo, Pixmap,
+        SamplingOptions, TileMode,
+    };
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    #[test]
+    fn from_bitmap_shares_immutable_pixels() {
+        let mut bitmap = Bitmap::new();
+        bitmap.alloc_n32_pixels((4, 4), None);
+        bitmap.erase_color(Color::RED);
+
+        let copied = Image::from_bitmap(&bitmap).unwrap();
+        bitmap.erase_color(Color::BLUE);
+        assert_eq!(copied.peek_pixels().unwrap().get_color((0, 0)), Color::RED);
+
+        bitmap.set_immutable();
+        let shared = Image::from_bitmap(&bitmap).unwrap();
+        unsafe {
+            assert_eq!(
+                shared.peek_pixels().unwrap().addr(),
+                bitmap.peek_pixels().unwrap().addr()
+            );
+        }
+        assert_eq!(shared.peek_pixels().unwrap().get_color((0, 0)), Color::BLUE);
+    }
+
+    #[test]
+    fn from_pixmap_copy() {
+        let info = ImageInfo::new((4, 4), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let mut pixels = vec![0u8; 4 * 4 * 4];
+        // pixel (1, 2) is red.
+        pixels[(2 * 4 + 1) * 4..(2 * 4 + 2) * 4].copy_from_slice(&[0xff, 0, 0, 0xff]);
+
+        let image = {
+            let pixmap = Pixmap::new(&info, &pixels, info.min_row_bytes());
+            Image::from_pixmap_copy(&pixmap).unwrap()
+        };
+        // the image does not depend on the original pixels.
+        drop(pixels);
+
+        assert_eq!(image.dimensions(), (4, 4).into());
+        let pixmap = image.peek_pixels().unwrap();
+        assert_eq!(pixmap.get_color((1, 2)), Color::RED);
+        assert_eq!(pixmap.get_color((0, 0)), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn decal_shader_is_transparent_outside_the_image() {
+        let mut sprite = crate::Surface::new_raster_n32_premul((4, 4)).unwrap();
+        sprite.canvas().clear(Color::RED);
+        let shader = sprite
+            .image_snapshot()
+            .to_shader(
+                (TileMode::Decal, TileMode::Decal),
+                SamplingOptions::default(),
+                None,
+            )
+            .unwrap();
+
+        let mut surface = crate::Surface::new_raster_n32_premul((16, 16)).unwrap();
+        let mut paint = crate::Paint::default();
+        paint.set_shader(shader);
+        surface
+            .canvas()
+            .clear(Color::TRANSPARENT)
+            .draw_paint(&paint);
+
+        let pixmap = surface.peek_pixels().unwrap();
+        assert_eq!(pixmap.get_color((2, 2)), Color::RED);
+        assert_eq!(pixmap.get_color((10, 2)), Color::TRANSPARENT);
+        assert_eq!(pixmap.get_color((2, 10)), Color::TRANSPARENT);
+        assert_eq!(pixmap.get_color((10, 10)), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn compute_hash_matches_pixmap() {
+        let mut surface = crate::Surface::new_raster_n32_premul((8, 8)).unwrap();
+        surface.canvas().clear(Color::RED);
+        let image = surface.image_snapshot();
+        let hash = image.compute_hash().unwrap();
+        assert_eq!(image.peek_pixels().unwrap().compute_hash().unwrap(), hash);
+        assert_eq!(surface.image_snapshot().compute_hash().unwrap(), hash);
+    }
+
+    #[test]
+    fn from_raster_calls_release() {
+        let info = ImageInfo::new((4, 4), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let pixels: &'static mut [u8] = Box::leak(vec![0xffu8; 4 * 4 * 4].into_boxed_slice());
+        let pixels_ptr = pixels.as_mut_ptr() as usize;
+        let released = Arc::new(AtomicBool::new(false));
+
+        let image = {
+            let pixmap = Pixmap::new(&info, pixels, info.min_row_bytes());
+            let released = released.clone();
+            unsafe {
+                Image::from_raster(&pixmap, move || {
+                    drop(Box::from_raw(std::slice::from_raw_parts_mut(
+                        pixels_ptr as *mut u8,
+                        4 * 4 * 4,
+                    )));
+                    released.store(true, Ordering::SeqCst);
+                })
+            }
+            .unwrap()
+        };
+
+        assert_eq!(image.peek_pixels().unwrap().get_color((3, 3)), Color::WHITE);
+        assert!(!released.load(Ordering::SeqCst));
+        drop(image);
+        assert!(released.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn lower_jpeg_quality_produces_smaller_data() {
+        let info = ImageInfo::new((64, 64), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let pixels: Vec<u8> = (0..64 * 64 * 4)
+            .map(|i: u32| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect();
+        let image =
+            Image::from_pixmap_copy(&Pixmap::new(&info, &pixels, info.min_row_bytes())).unwrap();
+
+        let full = image.encode_to_data(EncodedImageFormat::JPEG).unwrap();
+        let high = image
+            .encode_to_data_with_quality(EncodedImageFormat::JPEG, 100)
+            .unwrap();
+        let low = image
+            .encode_to_data_with_quality(EncodedImageFormat::JPEG, 20)
+            .unwrap();
+        assert_eq!(full.as_bytes(), high.as_bytes());
+        assert!(low.size() < high.size());
+
+        let clamped = image
+            .encode_to_data_with_quality(EncodedImageFormat::JPEG, 1000)
+            .unwrap();
+        assert_eq!(clamped.as_bytes(), high.as_bytes());
+    }
+}
@@ -824,7 +824,7 @@ pub const IDENTITY: Matrix = Matrix::new_identity();
 #[cfg(test)]
 mod tests {
     use super::{AffineMember, Matrix, TypeMask};
-    use crate::prelude::*;
+    use crate::{prelude::*, Vector};
 
     #[test]
     fn test_get_set_trait_compilation() {
@@ -852,4 +852,20 @@ mod tests {
             m.get_type()
         );
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn invert_and_map_vector() {
+        let mut m = Matrix::scale((2.0, 4.0));
+        m.post_translate((10.0, 20.0));
+        let inverted = m.invert().unwrap();
+        assert!(Matrix::concat(&m, &inverted).is_identity());
+        assert!(Matrix::concat(&inverted, &m).is_identity());
+
+        // vectors ignore the translation.
+        assert_eq!(m.map_vector((1.0, 1.0)), Vector::new(2.0, 4.0));
+        assert_eq!(inverted.map_vector((2.0, 4.0)), Vector::new(1.0, 1.0));
+
+        assert!(Matrix::scale((0.0, 1.0)).invert().is_none());
+    }
 }
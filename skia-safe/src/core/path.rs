@@ -1074,3 +1074,47 @@ fn test_path_rect() {
     let path = Path::rect(r, None);
     assert_eq!(*path.bounds(), r);
 }
+
+#[test]
+fn test_interpolate_triangles() {
+    let start = Path::polygon(
+        &[(0.0, 0.0).into(), (10.0, 0.0).into(), (0.0, 10.0).into()],
+        true,
+        None,
+        None,
+    );
+    let end = Path::polygon(
+        &[
+            (20.0, 20.0).into(),
+            (40.0, 20.0).into(),
+            (20.0, 30.0).into(),
+        ],
+        true,
+        None,
+        None,
+    );
+
+    let mid = start.interpolate(&end, 0.5).unwrap();
+    assert_eq!(mid.count_points(), 3);
+    assert_eq!(mid.get_point(0), Some(Point::new(10.0, 10.0)));
+    assert_eq!(mid.get_point(1), Some(Point::new(25.0, 10.0)));
+    assert_eq!(mid.get_point(2), Some(Point::new(10.0, 20.0)));
+
+    let open = Path::polygon(&[(0.0, 0.0).into(), (10.0, 0.0).into()], false, None, None);
+    assert!(start.interpolate(&open, 0.5).is_none());
+}
+
+#[test]
+fn test_is_interpolatable() {
+    let rect_a = Path::rect(Rect::new(0.0, 0.0, 10.0, 10.0), None);
+    let rect_b = Path::rect(Rect::new(5.0, 5.0, 50.0, 20.0), None);
+    let triangle = Path::polygon(
+        &[(0.0, 0.0).into(), (10.0, 0.0).into(), (0.0, 10.0).into()],
+        true,
+        None,
+        None,
+    );
+
+    assert!(rect_a.is_interpolatable(&rect_b));
+    assert!(!rect_a.is_interpolatable(&triangle));
+}
@@ -373,3 +373,29 @@ fn test_creation_snapshot_and_detach() {
     let _path = builder.snapshot();
     let _path = builder.detach();
 }
+
+#[test]
+fn test_even_odd_star_polygon() {
+    // A pentagram: the self intersecting outline encloses the center twice.
+    let points: Vec<Point> = (0..5)
+        .map(|i| {
+            let angle = (i * 2 % 5) as scalar * std::f32::consts::PI * 2.0 / 5.0;
+            Point::new(50.0 + 40.0 * angle.sin(), 50.0 - 40.0 * angle.cos())
+        })
+        .collect();
+
+    let mut builder = PathBuilder::new();
+    builder.inc_reserve(points.len(), points.len() + 1);
+    builder.add_polygon(&points, true);
+
+    let winding = builder.snapshot();
+    assert_eq!(winding.fill_type(), PathFillType::Winding);
+    assert!(winding.contains((50.0, 50.0)));
+
+    builder.set_fill_type(PathFillType::EvenOdd);
+    let even_odd = builder.detach();
+    assert_eq!(even_odd.fill_type(), PathFillType::EvenOdd);
+    assert!(!even_odd.contains((50.0, 50.0)));
+    // A tip of the star is still filled.
+    assert!(even_odd.contains((50.0, 15.0)));
+}
@@ -107,3 +107,57 @@ impl Picture {
         .unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Color, FilterMode, Paint, PictureRecorder, Rect, Surface, TileMode};
+
+    #[test]
+    fn approximate_op_count_and_bytes_used() {
+        let mut recorder = PictureRecorder::new();
+        let canvas = recorder.begin_recording(Rect::from_wh(100.0, 100.0), None);
+        let paint = Paint::default();
+        for i in 0..10 {
+            canvas.draw_rect(Rect::from_xywh(i as f32 * 10.0, 0.0, 5.0, 5.0), &paint);
+        }
+        let picture = recorder.finish_recording_as_picture(None).unwrap();
+
+        assert!(picture.approximate_op_count() >= 10);
+        assert!(picture.approximate_op_count_nested(true) >= 10);
+        assert!(picture.approximate_bytes_used() > 0);
+    }
+
+    #[test]
+    fn picture_shader_repeats_tile() {
+        // A 10x10 tile with a red square in its top left quadrant.
+        let mut recorder = PictureRecorder::new();
+        let canvas = recorder.begin_recording(Rect::from_wh(10.0, 10.0), None);
+        canvas.draw_rect(
+            Rect::from_wh(5.0, 5.0),
+            Paint::default().set_color(Color::RED),
+        );
+        let picture = recorder.finish_recording_as_picture(None).unwrap();
+        assert_eq!(picture.cull_rect(), Rect::from_wh(10.0, 10.0));
+
+        let shader = picture.to_shader(
+            (TileMode::Repeat, TileMode::Repeat),
+            FilterMode::Nearest,
+            None,
+            None,
+        );
+
+        let mut surface = Surface::new_raster_n32_premul((40, 40)).unwrap();
+        surface
+            .canvas()
+            .draw_paint(Paint::default().set_shader(shader));
+        let pixmap = surface.peek_pixels().unwrap();
+        for tile in 0..4 {
+            let offset = tile * 10;
+            assert_eq!(pixmap.get_color((offset + 2, offset + 2)), Color::RED);
+            assert_eq!(
+                pixmap.get_color((offset + 7, offset + 7)),
+                Color::TRANSPARENT
+            );
+        }
+    }
+}
@@ -76,6 +76,19 @@ impl Pixmap {
         .if_true_some(pixmap)
     }
 
+    /// Returns a [`Pixmap`] that shares the pixels of the area `area` of this [`Pixmap`].
+    ///
+    /// The returned [`Pixmap`] borrows from `self` and so can not outlive it. Returns `None` if
+    /// `area` does not intersect the bounds of this [`Pixmap`] or if there are no pixels.
+    pub fn subset(&self, area: impl AsRef<IRect>) -> Option<Borrows<Pixmap>> {
+        let mut pixmap = Pixmap::default();
+        unsafe {
+            self.native()
+                .extractSubset(pixmap.native_mut(), area.as_ref().native())
+        }
+        .if_true_then_some(|| pixmap.borrows(self))
+    }
+
     pub fn info(&self) -> &ImageInfo {
         ImageInfo::from_native_ref(&self.native().fInfo)
     }
@@ -143,6 +156,18 @@ impl Pixmap {
         Color::from_native_c(unsafe { self.native().getColor(p.x, p.y) })
     }
 
+    /// Returns the unpremultiplied color of the pixel at `p` without color space conversion.
+    ///
+    /// In contrast to [`Self::get_color()`], the color components are not quantized to 8 bits.
+    pub fn get_color4f(&self, p: impl Into<IPoint>) -> Color4f {
+        let p = p.into();
+        self.assert_pixel_exists(p);
+        let info = ImageInfo::new((1, 1), ColorType::RGBAF32, AlphaType::Unpremul, None);
+        let mut color = [Color4f::new(0.0, 0.0, 0.0, 0.0)];
+        assert!(self.read_pixels(&info, &mut color, info.min_row_bytes(), p));
+        color[0]
+    }
+
     pub fn get_alpha_f(&self, p: impl Into<IPoint>) -> f32 {
         let p = p.into();
         self.assert_pixel_exists(p);
@@ -207,6 +232,20 @@ impl Pixmap {
         }
     }
 
+    /// Computes a hash of the pixels that is stable across platforms, for example to compare
+    /// rendered output in tests. This is a rust-skia specific function.
+    ///
+    /// The pixels are converted to [`ColorType::RGBA8888`] and [`AlphaType::Premul`] before they
+    /// are hashed, so the hash does not depend on the native color type of the platform.
+    ///
+    /// Returns `None` if the pixels can not be read.
+    pub fn compute_hash(&self) -> Option<u64> {
+        let info = hashing::canonical_info(self.dimensions());
+        let mut pixels = vec![0u8; info.compute_min_byte_size()];
+        self.read_pixels(&info, &mut pixels, info.min_row_bytes(), (0, 0))
+            .if_true_then_some(|| hashing::hash(&info, &pixels))
+    }
+
     /// Access the underlying pixels as a byte array. This is a rust-skia specific function.
     pub fn bytes(&self) -> Option<&[u8]> {
         let addr = unsafe { self.addr() }.into_option()?;
@@ -355,3 +394,103 @@ unsafe impl Pixel for Color4f {
         ct == ColorType::RGBAF32
     }
 }
+
+pub(crate) mod hashing {
+    use crate::{AlphaType, ColorType, ISize, ImageInfo};
+
+    pub fn canonical_info(dimensions: ISize) -> ImageInfo {
+        ImageInfo::new(dimensions, ColorType::RGBA8888, AlphaType::Premul, None)
+    }
+
+    /// 64 bit FNV-1a of the dimensions and the pixels.
+    pub fn hash(info: &ImageInfo, pixels: &[u8]) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let dimensions = info.dimensions();
+        dimensions
+            .width
+            .to_le_bytes()
+            .iter()
+            .chain(dimensions.height.to_le_bytes().iter())
+            .chain(pixels)
+            .fold(OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlphaType, Color, Color4f, ColorType, IRect, ImageInfo, Paint, Pixmap, Surface};
+
+    #[test]
+    fn subset_shares_the_parent_pixels() {
+        let info = ImageInfo::new((100, 100), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let pixels: Vec<u8> = (0..100 * 100 * 4).map(|i| (i % 251) as u8).collect();
+        let pixmap = Pixmap::new(&info, &pixels, info.min_row_bytes());
+
+        let subset = pixmap.subset(IRect::from_xywh(20, 30, 10, 10)).unwrap();
+        assert_eq!(subset.dimensions(), (10, 10).into());
+        assert_eq!(subset.get_color((3, 4)), pixmap.get_color((23, 34)));
+        assert_eq!(unsafe { subset.addr_at((0, 0)) }, unsafe {
+            pixmap.addr_at((20, 30))
+        });
+
+        assert!(pixmap.subset(IRect::from_xywh(200, 200, 10, 10)).is_none());
+    }
+
+    #[test]
+    fn compute_hash_is_deterministic() {
+        fn render(color: Color) -> Option<u64> {
+            let mut surface = Surface::new_raster_n32_premul((16, 16)).unwrap();
+            let canvas = surface.canvas();
+            canvas.clear(Color::WHITE);
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true).set_color(color);
+            canvas.draw_circle((8, 8), 5.0, &paint);
+            let pixmap = surface.peek_pixels().unwrap();
+            pixmap.compute_hash()
+        }
+
+        let hash = render(Color::RED).unwrap();
+        assert_eq!(render(Color::RED).unwrap(), hash);
+        assert_ne!(render(Color::BLUE).unwrap(), hash);
+    }
+
+    #[test]
+    fn get_color_and_color4f() {
+        let info = ImageInfo::new((2, 2), ColorType::RGBA8888, AlphaType::Premul, None);
+        let pixels: [u8; 16] = [
+            0xff, 0, 0, 0xff, //
+            0, 0xff, 0, 0xff, //
+            0, 0, 0, 0, //
+            0x80, 0x80, 0x80, 0x80,
+        ];
+        let pixmap = Pixmap::new(&info, &pixels, info.min_row_bytes());
+        assert_eq!(pixmap.get_color((0, 0)), Color::RED);
+        assert_eq!(pixmap.get_color4f((0, 0)), Color4f::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(pixmap.get_color((1, 0)), Color::GREEN);
+        assert_eq!(pixmap.get_color4f((0, 1)), Color4f::new(0.0, 0.0, 0.0, 0.0));
+
+        let c = pixmap.get_color4f((1, 1));
+        assert!((c.a - 128.0 / 255.0).abs() < 0.001);
+        assert!((c.r - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn erase_and_scale_pixels() {
+        let mut src_surface = Surface::new_raster_n32_premul((4, 4)).unwrap();
+        let src = src_surface.peek_pixels().unwrap();
+        assert!(src.erase(Color::BLUE, None));
+        assert!(src.erase(Color::RED, Some(&IRect::from_xywh(0, 0, 2, 4))));
+        assert_eq!(src.get_color((1, 1)), Color::RED);
+        assert_eq!(src.get_color((3, 3)), Color::BLUE);
+
+        let mut dst_surface = Surface::new_raster_n32_premul((8, 8)).unwrap();
+        let dst = dst_surface.peek_pixels().unwrap();
+        assert!(src.scale_pixels(&dst, crate::SamplingOptions::default()));
+        assert_eq!(dst.get_color((1, 4)), Color::RED);
+        assert_eq!(dst.get_color((6, 4)), Color::BLUE);
+    }
+}
@@ -752,6 +752,21 @@ fn contains_overloads_compile() {
     r.contains(IRect::default());
 }
 
+#[test]
+fn round_and_round_out() {
+    let r = Rect::new(0.1, 0.1, 9.9, 9.9);
+    let rounded_out: IRect = r.round_out();
+    assert_eq!(rounded_out, IRect::new(0, 0, 10, 10));
+    let rounded_out: Rect = r.round_out();
+    assert_eq!(rounded_out, Rect::new(0.0, 0.0, 10.0, 10.0));
+    assert_eq!(r.round(), IRect::new(0, 0, 10, 10));
+    assert_eq!(r.round_in(), IRect::new(1, 1, 9, 9));
+    assert_eq!(
+        Rect::from(rounded_out.round()),
+        Rect::new(0.0, 0.0, 10.0, 10.0)
+    );
+}
+
 pub trait RoundOut<R> {
     fn round_out(&self) -> R;
 }
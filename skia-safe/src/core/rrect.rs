@@ -281,3 +281,37 @@ impl RRect {
         self.dump(true)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Corner, RRect, Type};
+    use crate::{Rect, Vector};
+
+    #[test]
+    fn distinct_corner_radii() {
+        let radii = [
+            Vector::new(1.0, 2.0),
+            Vector::new(3.0, 4.0),
+            Vector::new(5.0, 6.0),
+            Vector::new(7.0, 8.0),
+        ];
+        let rect = Rect::from_xywh(0.0, 0.0, 100.0, 50.0);
+        let rrect = RRect::new_rect_radii(rect, &radii);
+        assert_eq!(rrect.get_type(), Type::Complex);
+        assert_eq!(rrect.radii(Corner::UpperLeft), radii[0]);
+        assert_eq!(rrect.radii(Corner::UpperRight), radii[1]);
+        assert_eq!(rrect.radii(Corner::LowerRight), radii[2]);
+        assert_eq!(rrect.radii(Corner::LowerLeft), radii[3]);
+
+        assert!(rrect.contains(Rect::from_xywh(10.0, 10.0, 80.0, 30.0)));
+        assert!(!rrect.contains(Rect::from_xywh(0.0, 0.0, 1.0, 1.0)));
+
+        let inset = rrect.with_inset((1.0, 1.0));
+        assert_eq!(*inset.rect(), Rect::from_xywh(1.0, 1.0, 98.0, 48.0));
+        assert_eq!(inset.radii(Corner::LowerLeft), Vector::new(6.0, 7.0));
+
+        let outset = rrect.with_outset((1.0, 1.0));
+        assert_eq!(*outset.rect(), Rect::from_xywh(-1.0, -1.0, 102.0, 52.0));
+        assert_eq!(outset.radii(Corner::UpperLeft), Vector::new(2.0, 3.0));
+    }
+}
@@ -1,11 +1,11 @@
 #[cfg(feature = "gpu")]
 use crate::gpu;
 use crate::{
-    prelude::*, Bitmap, Canvas, DeferredDisplayList, IPoint, IRect, ISize, IVector, Image,
+    image, prelude::*, Bitmap, Canvas, DeferredDisplayList, IPoint, IRect, ISize, IVector, Image,
     ImageInfo, Paint, Pixmap, Point, SamplingOptions, SurfaceCharacterization, SurfaceProps,
 };
-use skia_bindings::{self as sb, SkRefCntBase, SkSurface};
-use std::{fmt, ptr};
+use skia_bindings::{self as sb, SkImage_AsyncReadResult, SkRefCntBase, SkSurface};
+use std::{ffi::c_void, fmt, ptr};
 
 pub use skia_bindings::SkSurface_ContentChangeMode as ContentChangeMode;
 variant_name!(ContentChangeMode::Retain, content_change_mode_naming);
@@ -83,9 +83,23 @@ impl Surface {
     }
 
     pub fn new_raster_n32_premul(size: impl Into<ISize>) -> Option<Self> {
+        Self::new_raster_n32_premul_with_props(size, None)
+    }
+
+    /// Creates a native 32 bit premultiplied raster surface that uses `surface_props`.
+    ///
+    /// A [`SurfaceProps`] with a known [`crate::PixelGeometry`] enables LCD text antialiasing.
+    pub fn new_raster_n32_premul_with_props(
+        size: impl Into<ISize>,
+        surface_props: Option<&SurfaceProps>,
+    ) -> Option<Self> {
         let size = size.into();
         Self::from_ptr(unsafe {
-            sb::C_SkSurface_MakeRasterN32Premul(size.width, size.height, ptr::null())
+            sb::C_SkSurface_MakeRasterN32Premul(
+                size.width,
+                size.height,
+                surface_props.native_ptr_or_null(),
+            )
         })
     }
 }
@@ -225,6 +239,11 @@ impl Surface {
         })
     }
 
+    /// Creates a GPU surface that matches `characterization`.
+    ///
+    /// The characterization is tied to the context it was created on. It can be used to recreate
+    /// surfaces on that context, but not on a new context after the original one was abandoned,
+    /// in that case `None` is returned.
     pub fn new_render_target_with_characterization(
         context: &mut gpu::RecordingContext,
         characterization: &SurfaceCharacterization,
@@ -264,10 +283,18 @@ impl Surface {
         info
     }
 
+    /// Returns a unique id that identifies the content of the surface. The id changes each time
+    /// the surface is drawn to, so it can be used to skip work on unchanged content.
     pub fn generation_id(&mut self) -> u32 {
         unsafe { self.native_mut().generationID() }
     }
 
+    /// Notifies the surface that its pixels are about to be changed outside of its [`Canvas`],
+    /// for example by writing to a backend texture. This changes the [`Self::generation_id()`]
+    /// and detaches existing snapshots.
+    ///
+    /// [`ContentChangeMode::Discard`] allows the surface to drop its current contents, while
+    /// [`ContentChangeMode::Retain`] keeps them.
     pub fn notify_content_will_change(&mut self, mode: ContentChangeMode) -> &mut Self {
         unsafe { self.native_mut().notifyContentWillChange(mode) }
         self
@@ -280,6 +307,11 @@ impl Surface {
         gpu::RecordingContext::from_unshared_ptr(unsafe { self.native_mut().recordingContext() })
     }
 
+    /// Returns the [`gpu::BackendTexture`] of a GPU backed surface, so that it can be handed to
+    /// another subsystem. Returns `None` if the surface is not backed by a texture.
+    ///
+    /// `handle_access` specifies how the texture is going to be accessed and implies the flush
+    /// that happens before it is returned.
     pub fn get_backend_texture(
         &mut self,
         handle_access: BackendHandleAccess,
@@ -296,6 +328,10 @@ impl Surface {
         }
     }
 
+    /// Returns the [`gpu::BackendRenderTarget`] of a GPU backed surface, so that it can be handed
+    /// to another subsystem. Returns `None` if the surface is not backed by a render target.
+    ///
+    /// See [`Self::get_backend_texture()`] for the semantics of `handle_access`.
     pub fn get_backend_render_target(
         &mut self,
         handle_access: BackendHandleAccess,
@@ -346,6 +382,13 @@ impl Surface {
         Canvas::borrow_from_native_mut(canvas_ref)
     }
 
+    /// Runs `f` with the surface's [`Canvas`] and returns the surface, so that it can be flushed
+    /// afterwards, for example with `surface.with_canvas(|c| ...).flush_and_submit()`.
+    pub fn with_canvas(&mut self, f: impl FnOnce(&mut Canvas)) -> &mut Self {
+        f(self.canvas());
+        self
+    }
+
     // TODO: why is self mutable here?
     pub fn new_surface(&mut self, info: &ImageInfo) -> Option<Surface> {
         Surface::from_ptr(unsafe { sb::C_SkSurface_makeSurface(self.native_mut(), info.native()) })
@@ -358,6 +401,10 @@ impl Surface {
         })
     }
 
+    /// Returns an [`Image`] capturing the current surface contents.
+    ///
+    /// The [`Image`] is backed by the same kind of memory as the surface: snapshots of GPU
+    /// surfaces stay texture backed and do not involve a CPU round trip.
     pub fn image_snapshot(&mut self) -> Image {
         Image::from_ptr(unsafe {
             sb::C_SkSurface_makeImageSnapshot(self.native_mut(), ptr::null())
@@ -365,6 +412,10 @@ impl Surface {
         .unwrap()
     }
 
+    /// Like [`Self::image_snapshot()`], but only captures the area `bounds`, which is
+    /// intersected with the surface bounds. Snapshots of GPU surfaces stay texture backed.
+    ///
+    /// Returns `None` if `bounds` does not intersect the surface.
     // TODO: combine this function with image_snapshot and make bounds optional()?
     pub fn image_snapshot_with_bounds(&mut self, bounds: impl AsRef<IRect>) -> Option<Image> {
         Image::from_ptr(unsafe {
@@ -433,8 +484,36 @@ impl Surface {
         unsafe { self.native_mut().readPixels2(bitmap.native(), src.x, src.y) }
     }
 
-    // TODO: AsyncReadResult, RescaleGamma (m79, m86)
-    // TODO: wrap asyncRescaleAndReadPixels (m76, m79, m89)
+    /// Reads the pixels of `src_rect` asynchronously and rescales them to the dimensions of
+    /// `info`.
+    ///
+    /// On GPU backed surfaces the rescaling happens on the GPU, so only the smaller result is
+    /// transferred. `callback` receives the result or `None` if the read failed. It may be
+    /// called synchronously, or later, for example when the GPU context is submitted.
+    pub fn async_rescale_and_read_pixels<F>(
+        &mut self,
+        info: &ImageInfo,
+        src_rect: impl AsRef<IRect>,
+        rescale_gamma: image::RescaleGamma,
+        rescale_mode: image::RescaleMode,
+        callback: F,
+    ) where
+        F: FnOnce(Option<&image::AsyncReadResult>) + 'static,
+    {
+        let context = Box::into_raw(Box::new(callback));
+        unsafe {
+            sb::C_SkSurface_asyncRescaleAndReadPixels(
+                self.native_mut(),
+                info.native(),
+                src_rect.as_ref().native(),
+                rescale_gamma,
+                rescale_mode,
+                Some(async_read_pixels_callback::<F>),
+                context as _,
+            )
+        }
+    }
+
     // TODO: wrap asyncRescaleAndReadPixelsYUV420 (m77, m79, m89)
 
     pub fn write_pixels_from_pixmap(&mut self, src: &Pixmap, dst: impl Into<IPoint>) {
@@ -480,19 +559,28 @@ impl Surface {
         access: BackendSurfaceAccess,
         info: &gpu::FlushInfo,
     ) -> gpu::SemaphoresSubmitted {
-        unsafe { self.native_mut().flush(access, info.native()) }
+        let is_gpu = self.recording_context().is_some();
+        let surface = self.native_mut();
+        info.flush_with(is_gpu, |info| unsafe { surface.flush(access, info) })
     }
 
+    /// Flushes the surface and transitions its backend texture or render target to `new_state`,
+    /// for example to the present layout of a Vulkan swapchain image.
+    ///
+    /// Semaphores set with [`gpu::FlushInfo::set_signal_semaphores()`] are signaled after the
+    /// flushed work completed, which lets a presentation engine wait on the GPU instead of the
+    /// CPU. Returns [`gpu::SemaphoresSubmitted::No`] if the semaphores could not be submitted.
     #[cfg(feature = "gpu")]
     pub fn flush_with_mutable_state<'a>(
         &mut self,
         info: &gpu::FlushInfo,
         new_state: impl Into<Option<&'a gpu::BackendSurfaceMutableState>>,
     ) -> gpu::SemaphoresSubmitted {
-        unsafe {
-            self.native_mut()
-                .flush1(info.native(), new_state.into().native_ptr_or_null())
-        }
+        let is_gpu = self.recording_context().is_some();
+        let surface = self.native_mut();
+        info.flush_with(is_gpu, |info| unsafe {
+            surface.flush1(info, new_state.into().native_ptr_or_null())
+        })
     }
 
     // TODO: wait()
@@ -526,6 +614,16 @@ impl Surface {
     }
 }
 
+unsafe extern "C" fn async_read_pixels_callback<F>(
+    context: *mut c_void,
+    result: *const SkImage_AsyncReadResult,
+) where
+    F: FnOnce(Option<&image::AsyncReadResult>),
+{
+    let callback = Box::from_raw(context as *mut F);
+    callback(result.as_ref().map(image::AsyncReadResult::from_native_ref))
+}
+
 #[test]
 fn create() {
     assert!(Surface::new_raster_n32_premul((0, 0)).is_none());
@@ -575,3 +673,202 @@ fn test_drawing_owned_as_exclusive_ref_ergonomics() {
         surface.draw(canvas, (10.0, 10.0), SamplingOptions::default(), None);
     }
 }
+
+#[test]
+fn test_async_rescale_and_read_pixels() {
+    use std::{cell::Cell, rc::Rc};
+
+    let mut surface = Surface::new_raster_n32_premul((256, 256)).unwrap();
+    surface.canvas().clear(crate::Color::RED);
+
+    let info = ImageInfo::new_n32_premul((32, 32), None);
+    let read = Rc::new(Cell::new(false));
+    let callback_info = info.clone();
+    let callback_read = read.clone();
+    surface.async_rescale_and_read_pixels(
+        &info,
+        IRect::from_wh(256, 256),
+        image::RescaleGamma::Src,
+        image::RescaleMode::RepeatedLinear,
+        move |result| {
+            let info = callback_info;
+            let result = result.unwrap();
+            assert_eq!(result.count(), 1);
+            let row_bytes = result.row_bytes(0);
+            assert!(row_bytes >= info.min_row_bytes());
+            let pixels = unsafe {
+                std::slice::from_raw_parts(
+                    result.data(0) as *const u8,
+                    info.compute_byte_size(row_bytes),
+                )
+            };
+            let pixmap = Pixmap::new(&info, pixels, row_bytes);
+            assert_eq!(pixmap.dimensions(), ISize::new(32, 32));
+            assert_eq!(pixmap.get_color((16, 16)), crate::Color::RED);
+            callback_read.set(true);
+        },
+    );
+    // raster surfaces read synchronously.
+    assert!(read.get());
+}
+
+#[test]
+fn test_raster_with_pixel_geometry() {
+    use crate::{PixelGeometry, SurfacePropsFlags};
+
+    let props = SurfaceProps::new(SurfacePropsFlags::default(), PixelGeometry::RGBH);
+    let info = ImageInfo::new_n32_premul((64, 32), None);
+    let surface = Surface::new_raster(&info, None, Some(&props)).unwrap();
+    assert_eq!(surface.props().pixel_geometry(), PixelGeometry::RGBH);
+
+    let surface_n32 = Surface::new_raster_n32_premul_with_props((64, 32), Some(&props)).unwrap();
+    assert_eq!(surface_n32.props().pixel_geometry(), PixelGeometry::RGBH);
+}
+
+#[test]
+fn test_generation_id_changes_on_draw() {
+    let mut surface = Surface::new_raster_n32_premul((4, 4)).unwrap();
+    let generation_id = surface.generation_id();
+    assert_eq!(surface.generation_id(), generation_id);
+
+    surface.canvas().clear(crate::Color::RED);
+    let after_draw = surface.generation_id();
+    assert_ne!(after_draw, generation_id);
+    assert_eq!(surface.generation_id(), after_draw);
+}
+
+#[test]
+fn test_with_canvas() {
+    let mut surface = Surface::new_raster_n32_premul((4, 4)).unwrap();
+    surface
+        .with_canvas(|canvas| {
+            canvas.clear(crate::Color::RED);
+        })
+        .flush_and_submit();
+    assert_eq!(
+        surface.peek_pixels().unwrap().get_color((2, 2)),
+        crate::Color::RED
+    );
+}
+
+#[test]
+fn test_notify_content_will_change() {
+    let mut surface = Surface::new_raster_n32_premul((4, 4)).unwrap();
+    surface.canvas().clear(crate::Color::RED);
+    let snapshot = surface.image_snapshot();
+    let generation_id = surface.generation_id();
+
+    surface.notify_content_will_change(ContentChangeMode::Discard);
+    assert_ne!(surface.generation_id(), generation_id);
+    surface.canvas().clear(crate::Color::BLUE);
+
+    let fresh = surface.image_snapshot();
+    assert_ne!(fresh.unique_id(), snapshot.unique_id());
+    let color = |image: &Image| image.peek_pixels().unwrap().get_color((0, 0));
+    assert_eq!(color(&snapshot), crate::Color::RED);
+    assert_eq!(color(&fresh), crate::Color::BLUE);
+}
+
+#[test]
+fn test_read_pixels_converts_to_unpremul() {
+    let mut surface = Surface::new_raster_n32_premul((2, 2)).unwrap();
+    surface
+        .canvas()
+        .clear(crate::Color::from_argb(0x80, 0xff, 0x00, 0x00));
+
+    let info = ImageInfo::new(
+        (2, 2),
+        crate::ColorType::RGBA8888,
+        crate::AlphaType::Unpremul,
+        None,
+    );
+    let mut pixels = [0u8; 2 * 2 * 4];
+    assert!(surface.read_pixels(&info, &mut pixels, info.min_row_bytes(), (0, 0)));
+    assert_eq!(pixels[..4], [0xff, 0x00, 0x00, 0x80]);
+
+    let info = info.with_alpha_type(crate::AlphaType::Premul);
+    assert!(surface.read_pixels(&info, &mut pixels, info.min_row_bytes(), (0, 0)));
+    assert_eq!(pixels[..4], [0x80, 0x00, 0x00, 0x80]);
+}
+
+#[test]
+fn test_image_snapshot_with_bounds() {
+    let mut surface = Surface::new_raster_n32_premul((16, 16)).unwrap();
+    surface.canvas().clear(crate::Color::RED);
+
+    let image = surface
+        .image_snapshot_with_bounds(IRect::from_xywh(4, 4, 8, 8))
+        .unwrap();
+    assert_eq!(image.dimensions(), ISize::new(8, 8));
+    assert!(!image.is_texture_backed());
+
+    let clipped = surface
+        .image_snapshot_with_bounds(IRect::from_xywh(12, 12, 8, 8))
+        .unwrap();
+    assert_eq!(clipped.dimensions(), ISize::new(4, 4));
+
+    assert!(surface
+        .image_snapshot_with_bounds(IRect::from_xywh(20, 20, 8, 8))
+        .is_none());
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn test_image_snapshot_with_bounds_on_render_target() {
+    let mut context = gpu::DirectContext::new_mock(None).unwrap();
+    let info = ImageInfo::new_n32_premul((16, 16), None);
+    let mut surface = Surface::new_render_target(
+        &mut context,
+        crate::Budgeted::Yes,
+        &info,
+        None,
+        gpu::SurfaceOrigin::TopLeft,
+        None,
+        None,
+    )
+    .unwrap();
+    surface.canvas().clear(crate::Color::RED);
+
+    let image = surface
+        .image_snapshot_with_bounds(IRect::from_xywh(4, 4, 8, 8))
+        .unwrap();
+    assert_eq!(image.dimensions(), ISize::new(8, 8));
+    assert!(image.is_texture_backed());
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn test_recreate_surface_from_characterization() {
+    let mut context = gpu::DirectContext::new_mock(None).unwrap();
+    let info = ImageInfo::new_n32_premul((32, 32), None);
+    let surface = Surface::new_render_target(
+        &mut context,
+        crate::Budgeted::Yes,
+        &info,
+        None,
+        gpu::SurfaceOrigin::TopLeft,
+        None,
+        None,
+    )
+    .unwrap();
+    let characterization = surface.characterize().unwrap();
+    drop(surface);
+
+    let recreated = Surface::new_render_target_with_characterization(
+        &mut context,
+        &characterization,
+        crate::Budgeted::Yes,
+    )
+    .unwrap();
+    assert!(recreated.is_compatible(&characterization));
+    drop(recreated);
+
+    context.abandon();
+    let mut new_context = gpu::DirectContext::new_mock(None).unwrap();
+    assert!(Surface::new_render_target_with_characterization(
+        &mut new_context,
+        &characterization,
+        crate::Budgeted::Yes
+    )
+    .is_none());
+}
@@ -43,6 +43,8 @@ bitflags! {
     pub struct SurfacePropsFlags: u32 {
         const USE_DEVICE_INDEPENDENT_FONTS =
             sb::SkSurfaceProps_Flags_kUseDeviceIndependentFonts_Flag as u32;
+        /// Use internal MSAA to render to non-MSAA GPU surfaces.
+        const DYNAMIC_MSAA = sb::SkSurfaceProps_Flags_kDynamicMSAA_Flag as u32;
     }
 }
 
@@ -112,6 +114,10 @@ impl SurfaceProps {
         self.flags()
             .contains(SurfacePropsFlags::USE_DEVICE_INDEPENDENT_FONTS)
     }
+
+    pub fn is_dynamic_msaa(self) -> bool {
+        self.flags().contains(SurfacePropsFlags::DYNAMIC_MSAA)
+    }
 }
 
 #[test]
@@ -127,3 +133,16 @@ fn create() {
     assert_eq!(PixelGeometry::RGBH, props.pixel_geometry());
     assert!(props.is_use_device_independent_fonts());
 }
+
+#[test]
+fn create_with_dynamic_msaa() {
+    let props = SurfaceProps::new(
+        SurfacePropsFlags::USE_DEVICE_INDEPENDENT_FONTS | SurfacePropsFlags::DYNAMIC_MSAA,
+        PixelGeometry::Unknown,
+    );
+    assert!(props.is_dynamic_msaa());
+    assert!(props.is_use_device_independent_fonts());
+
+    let surface = crate::Surface::new_raster_n32_premul_with_props((16, 16), Some(&props)).unwrap();
+    assert_eq!(*surface.props(), props);
+}
@@ -407,3 +407,26 @@ fn test_point_size_equals_size_of_two_scalars_used_in_alloc_run_pos() {
     use std::mem;
     assert_eq!(mem::size_of::<Point>(), mem::size_of::<[scalar; 2]>())
 }
+
+#[test]
+fn test_build_blob_run_by_run() {
+    let font = Font::default();
+    let mut builder = TextBlobBuilder::new();
+    assert!(builder.make().is_none());
+
+    builder
+        .alloc_run(&font, 2, (10.0, 20.0), None)
+        .copy_from_slice(&[1, 2]);
+    let (glyphs, positions) = builder.alloc_run_pos(&font, 1, None);
+    glyphs[0] = 3;
+    positions[0] = Point::new(30.0, 20.0);
+
+    let blob = builder.make().unwrap();
+    let runs: Vec<Vec<GlyphId>> = TextBlobIter::new(&blob)
+        .map(|run| run.glyph_indices.to_vec())
+        .collect();
+    assert_eq!(runs, [vec![1, 2], vec![3]]);
+
+    // make() resets the builder.
+    assert!(builder.make().is_none());
+}
@@ -3,7 +3,8 @@ use crate::{
     font_parameters::VariationAxis,
     interop::{self, MemoryStream, NativeStreamBase, StreamAsset},
     prelude::*,
-    Data, FontArguments, FontStyle, GlyphId, Rect, Unichar,
+    scalar, Data, Font, FontArguments, FontHinting, FontStyle, GlyphId, Matrix, Rect, Unichar,
+    Vector,
 };
 use skia_bindings::{self as sb, SkRefCntBase, SkTypeface, SkTypeface_LocalizedStrings};
 use std::{ffi, fmt, ptr};
@@ -207,6 +208,16 @@ impl Typeface {
         Data::from_ptr(unsafe { sb::C_SkTypeface_copyTableData(self.native(), tag) })
     }
 
+    /// Returns the tags and the data of all the tables of the typeface, or an empty `Vec` if
+    /// the tags can not be read.
+    pub fn read_all_tables(&self) -> Vec<(FontTableTag, Data)> {
+        self.table_tags()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|tag| Some((tag, self.copy_table_data(tag)?)))
+            .collect()
+    }
+
     pub fn units_per_em(&self) -> Option<i32> {
         let units = unsafe { self.native().getUnitsPerEm() };
         if units != 0 {
@@ -263,13 +274,98 @@ impl Typeface {
             })
     }
 
-    // TODO: createScalerContext()
+    /// Returns a [`ScalerContext`] that measures glyphs of this typeface at `text_size`,
+    /// transformed by `matrix`.
+    pub fn scaler_context(&self, text_size: scalar, matrix: &Matrix) -> ScalerContext {
+        ScalerContext::new(self, text_size, matrix)
+    }
 
     pub fn bounds(&self) -> Rect {
         Rect::from_native_c(unsafe { sb::C_SkTypeface_getBounds(self.native()) })
     }
 }
 
+/// Measures the glyphs of a [`Typeface`] at an exact text size and transformation.
+///
+/// Skia m92 keeps `SkScalerContext` private, so the metrics are computed by an unhinted [`Font`]
+/// with linear metrics and then mapped by the matrix.
+#[derive(Clone, Debug)]
+pub struct ScalerContext {
+    typeface: Typeface,
+    text_size: scalar,
+    matrix: Matrix,
+}
+
+/// The advance and bounds of a glyph, see [`ScalerContext::metrics()`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GlyphMetrics {
+    pub advance: Vector,
+    pub bounds: Rect,
+}
+
+impl ScalerContext {
+    fn new(typeface: &Typeface, text_size: scalar, matrix: &Matrix) -> Self {
+        Self {
+            typeface: typeface.clone(),
+            text_size,
+            matrix: *matrix,
+        }
+    }
+
+    pub fn typeface(&self) -> &Typeface {
+        &self.typeface
+    }
+
+    pub fn text_size(&self) -> scalar {
+        self.text_size
+    }
+
+    pub fn matrix(&self) -> &Matrix {
+        &self.matrix
+    }
+
+    /// Returns the advance of `glyph`, transformed by the matrix.
+    pub fn advance(&self, glyph: GlyphId) -> Vector {
+        self.metrics(glyph).advance
+    }
+
+    /// Returns the advance and the bounds of `glyph`, transformed by the matrix.
+    pub fn metrics(&self, glyph: GlyphId) -> GlyphMetrics {
+        let mut widths = [0.0];
+        let mut bounds = [Rect::default()];
+        self.font(self.text_size).get_widths_bounds(
+            &[glyph],
+            Some(&mut widths),
+            Some(&mut bounds),
+            None,
+        );
+        GlyphMetrics {
+            advance: self.matrix.map_vector((widths[0], 0.0)),
+            bounds: self.matrix.map_rect(bounds[0]).0,
+        }
+    }
+
+    /// Returns the horizontal advance of `glyph` in the design units of the typeface, ignoring
+    /// the text size and the matrix.
+    ///
+    /// Returns `None` if the typeface does not report its units per em.
+    pub fn design_advance(&self, glyph: GlyphId) -> Option<scalar> {
+        let units_per_em = self.typeface.units_per_em()?;
+        let mut widths = [0.0];
+        self.font(units_per_em as scalar)
+            .get_widths(&[glyph], &mut widths);
+        Some(widths[0])
+    }
+
+    fn font(&self, size: scalar) -> Font {
+        let mut font = Font::from_typeface(self.typeface.clone(), size);
+        font.set_hinting(FontHinting::None)
+            .set_subpixel(true)
+            .set_linear_metrics(true);
+        font
+    }
+}
+
 pub type LocalizedStringsIter = RefHandle<SkTypeface_LocalizedStrings>;
 
 impl NativeDrop for SkTypeface_LocalizedStrings {
@@ -307,6 +403,7 @@ impl Iterator for LocalizedStringsIter {
 #[cfg(test)]
 mod tests {
     use super::{SerializeBehavior, Typeface};
+    use crate::{Data, FontStyle, Matrix, Vector};
 
     #[test]
     fn serialize_and_deserialize_default_typeface() {
@@ -340,10 +437,64 @@ mod tests {
         assert!(any);
     }
 
+    #[test]
+    fn read_all_tables_of_default() {
+        let tf = Typeface::default();
+        let tables = tf.read_all_tables();
+        let tags = tf.table_tags().unwrap_or_default();
+        assert_eq!(tables.iter().map(|(tag, _)| *tag).collect::<Vec<_>>(), tags);
+        for (tag, data) in tables {
+            assert_eq!(Some(data.size()), tf.get_table_size(tag));
+        }
+    }
+
     #[test]
     fn get_font_data_of_default() {
         let tf = Typeface::default();
         let (data, _ttc_index) = tf.to_font_data().unwrap();
         assert!(!data.is_empty());
     }
+
+    #[test]
+    fn from_data_with_ttc_index() {
+        let tf = Typeface::default();
+        let (data, ttc_index) = tf.to_font_data().unwrap();
+        let loaded = Typeface::from_data(Data::new_copy(&data), ttc_index).unwrap();
+        assert_eq!(loaded.family_name(), tf.family_name());
+        assert_eq!(loaded.count_glyphs(), tf.count_glyphs());
+    }
+
+    #[test]
+    fn scaler_context_advances_of_monospace_glyphs_are_equal() {
+        let tf = [
+            "monospace",
+            "Courier New",
+            "DejaVu Sans Mono",
+            "Menlo",
+            "Consolas",
+        ]
+        .iter()
+        .filter_map(|name| Typeface::from_name(name, FontStyle::normal()))
+        .find(|tf| tf.is_fixed_pitch())
+        .expect("no monospace typeface available");
+        let i = tf.unichar_to_glyph('i' as _);
+        let w = tf.unichar_to_glyph('W' as _);
+
+        let context = tf.scaler_context(16.0, &Matrix::default());
+        let advance = context.advance(i);
+        assert!(advance.x > 0.0);
+        assert_eq!(advance, context.advance(w));
+        assert_eq!(context.metrics(w).advance, advance);
+
+        let scaled = tf.scaler_context(16.0, &Matrix::scale((2.0, 2.0)));
+        assert_eq!(scaled.advance(i), Vector::new(advance.x * 2.0, 0.0));
+
+        let design_advance = context.design_advance(i).unwrap();
+        assert_eq!(context.design_advance(w), Some(design_advance));
+        assert_eq!(
+            tf.scaler_context(64.0, &Matrix::default())
+                .design_advance(i),
+            Some(design_advance)
+        );
+    }
 }
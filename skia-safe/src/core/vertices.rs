@@ -56,7 +56,30 @@ impl Vertices {
         let vertex_count = positions.len();
         assert_eq!(texs.len(), vertex_count);
         assert_eq!(colors.len(), vertex_count);
+        Self::new_copy_optional(mode, positions, Some(texs), Some(colors), indices)
+    }
+
+    /// Creates [`Vertices`] by copying `positions` and the optional `texs`, `colors`, and
+    /// `indices`.
+    ///
+    /// Panics if `texs` or `colors` are provided and do not contain one entry per position.
+    pub fn new_copy_optional(
+        mode: VertexMode,
+        positions: &[Point],
+        texs: Option<&[Point]>,
+        colors: Option<&[Color]>,
+        indices: Option<&[u16]>,
+    ) -> Vertices {
+        let vertex_count = positions.len();
+        if let Some(texs) = texs {
+            assert_eq!(texs.len(), vertex_count);
+        }
+        if let Some(colors) = colors {
+            assert_eq!(colors.len(), vertex_count);
+        }
 
+        let texs_ptr = texs.map(|t| t.native().as_ptr()).unwrap_or(ptr::null());
+        let colors_ptr = colors.map(|c| c.native().as_ptr()).unwrap_or(ptr::null());
         let indices_ptr = indices.map(|i| i.as_ptr()).unwrap_or(ptr::null());
         let indices_count = indices.map(|i| i.len()).unwrap_or(0);
 
@@ -65,8 +88,8 @@ impl Vertices {
                 mode,
                 vertex_count as _,
                 positions.native().as_ptr(),
-                texs.native().as_ptr(),
-                colors.native().as_ptr(),
+                texs_ptr,
+                colors_ptr,
                 indices_count.try_into().unwrap(),
                 indices_ptr,
             )
@@ -294,3 +317,45 @@ impl Builder {
         Vertices::from_ptr(unsafe { sb::C_SkVertices_Builder_detach(self.native_mut()) }).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{VertexMode, Vertices};
+    use crate::{BlendMode, Color, Paint, Point, Surface};
+
+    #[test]
+    fn draw_gradient_quad_as_two_triangles() {
+        let positions = [
+            Point::new(0.0, 0.0),
+            Point::new(100.0, 0.0),
+            Point::new(100.0, 100.0),
+            Point::new(0.0, 100.0),
+        ];
+        let colors = [Color::RED, Color::BLUE, Color::BLUE, Color::RED];
+        let indices = [0, 1, 2, 0, 2, 3];
+        let vertices = Vertices::new_copy_optional(
+            VertexMode::Triangles,
+            &positions,
+            None,
+            Some(&colors[..]),
+            Some(&indices[..]),
+        );
+        assert!(vertices.has_colors());
+        assert!(!vertices.has_tex_coords());
+        assert_eq!(vertices.index_count(), 6);
+
+        let mut surface = Surface::new_raster_n32_premul((100, 100)).unwrap();
+        surface
+            .canvas()
+            .draw_vertices(&vertices, BlendMode::Modulate, Some(&Paint::default()));
+
+        let pixmap = surface.peek_pixels().unwrap();
+        // The center lies on the diagonal both triangles share. The colors only vary along x, so
+        // it has the same color whichever triangle covers it.
+        let center = pixmap.get_color((50, 50));
+        assert!((i32::from(center.r()) - 0x80).abs() <= 8);
+        assert!((i32::from(center.b()) - 0x80).abs() <= 8);
+        assert_eq!(center.g(), 0);
+        assert_eq!(center.a(), 0xff);
+    }
+}
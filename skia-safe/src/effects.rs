@@ -26,7 +26,7 @@ pub mod overdraw_color_filter;
 pub mod perlin_noise_shader;
 
 pub mod runtime_effect;
-pub use runtime_effect::RuntimeEffect;
+pub use runtime_effect::{RuntimeEffect, RuntimeShaderBuilder};
 
 pub mod shader_mask_filter;
 pub mod stroke_and_fill_path_effect;
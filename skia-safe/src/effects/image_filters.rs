@@ -907,7 +907,10 @@ impl Picture {
 #[cfg(test)]
 mod tests {
     use super::CropRect;
-    use crate::{IRect, Rect};
+    use crate::{
+        image_filter::MapDirection, Color, Contains, Font, IRect, Image, ImageFilter, Matrix,
+        Paint, Rect, SamplingOptions, Surface,
+    };
 
     fn cr(crop_rect: impl Into<CropRect>) -> CropRect {
         crop_rect.into()
@@ -935,4 +938,146 @@ mod tests {
         assert_eq!(cr(rect), CropRect(rect));
         assert_eq!(cr(&rect), CropRect(rect));
     }
+
+    /// Draws text with an optional image filter and returns the bounds of all non-white pixels
+    /// and whether red pixels were drawn.
+    fn draw_text(filter: Option<ImageFilter>) -> (Option<IRect>, bool) {
+        let mut surface = Surface::new_raster_n32_premul((120, 80)).unwrap();
+        let canvas = surface.canvas();
+        canvas.clear(Color::WHITE);
+        let mut paint = Paint::default();
+        paint.set_color(Color::RED).set_image_filter(filter);
+        canvas.draw_str(
+            "Hi",
+            (10, 50),
+            &Font::default().with_size(40.0).unwrap(),
+            &paint,
+        );
+
+        let pixmap = surface.peek_pixels().unwrap();
+        let mut bounds: Option<IRect> = None;
+        let mut red = false;
+        for y in 0..pixmap.height() {
+            for x in 0..pixmap.width() {
+                let c = pixmap.get_color((x, y));
+                if c == Color::WHITE {
+                    continue;
+                }
+                red |= c.r() > 0xc0 && c.g() < 0x40 && c.b() < 0x40;
+                let pixel = IRect::from_xywh(x, y, 1, 1);
+                bounds = Some(bounds.map_or(pixel, |b| IRect::join(&b, &pixel)));
+            }
+        }
+        (bounds, red)
+    }
+
+    #[test]
+    fn drop_shadow_on_text() {
+        let (text_bounds, _) = draw_text(None);
+        let text_bounds = match text_bounds {
+            Some(bounds) => bounds,
+            // no default typeface available.
+            None => return,
+        };
+
+        let (shadow_bounds, shadow_red) = draw_text(super::drop_shadow_only(
+            (8.0, 8.0),
+            (0.5, 0.5),
+            Color::BLACK,
+            None,
+            None,
+        ));
+        let shadow_bounds = shadow_bounds.unwrap();
+        assert!(!shadow_red);
+        assert!((shadow_bounds.left - (text_bounds.left + 8)).abs() <= 3);
+        assert!((shadow_bounds.top - (text_bounds.top + 8)).abs() <= 3);
+
+        let (both_bounds, both_red) = draw_text(super::drop_shadow(
+            (8.0, 8.0),
+            (0.5, 0.5),
+            Color::BLACK,
+            None,
+            None,
+        ));
+        let both_bounds = both_bounds.unwrap();
+        assert!(both_red);
+        assert!(both_bounds.contains(&text_bounds));
+        assert!(both_bounds.right > text_bounds.right);
+        assert!(both_bounds.bottom > text_bounds.bottom);
+    }
+
+    /// A 40x40 image with a red left half and a blue right half.
+    fn red_blue_image() -> Image {
+        let mut surface = Surface::new_raster_n32_premul((40, 40)).unwrap();
+        let canvas = surface.canvas();
+        canvas.clear(Color::BLUE);
+        canvas.draw_rect(
+            Rect::from_xywh(0.0, 0.0, 20.0, 40.0),
+            Paint::default().set_color(Color::RED),
+        );
+        surface.image_snapshot()
+    }
+
+    fn draw_filtered(filter: Option<ImageFilter>) -> Surface {
+        let mut surface = Surface::new_raster_n32_premul((40, 40)).unwrap();
+        let mut paint = Paint::default();
+        paint.set_image_filter(filter);
+        surface
+            .canvas()
+            .draw_image(&red_blue_image(), (0, 0), Some(&paint));
+        surface
+    }
+
+    #[test]
+    fn magnifier_enlarges_source_rect() {
+        let bounds = Rect::from_wh(40.0, 40.0);
+        let mut unfiltered = draw_filtered(None);
+        assert_eq!(
+            unfiltered.peek_pixels().unwrap().get_color((30, 20)),
+            Color::BLUE
+        );
+
+        // Magnify the top left quarter by 2x, so that the red half covers the whole surface.
+        let filter = super::magnifier(Rect::from_wh(20.0, 20.0), 1.0, None, bounds).unwrap();
+        let mut magnified = draw_filtered(Some(filter));
+        let pixmap = magnified.peek_pixels().unwrap();
+        assert_eq!(pixmap.get_color((10, 20)), Color::RED);
+        assert_eq!(pixmap.get_color((30, 20)), Color::RED);
+    }
+
+    #[test]
+    fn tile_repeats_source_rect() {
+        let filter =
+            super::tile(Rect::from_wh(10.0, 40.0), Rect::from_wh(40.0, 40.0), None).unwrap();
+        let mut tiled = draw_filtered(Some(filter));
+        let pixmap = tiled.peek_pixels().unwrap();
+        for x in (0..40).step_by(5) {
+            assert_eq!(pixmap.get_color((x, 20)), Color::RED);
+        }
+    }
+
+    #[test]
+    fn matrix_transform_scales_bounds() {
+        let filter =
+            super::matrix_transform(&Matrix::scale((2.0, 2.0)), SamplingOptions::default(), None)
+                .unwrap();
+        assert_eq!(
+            filter.compute_fast_bounds(Rect::from_wh(10.0, 20.0)),
+            Rect::from_wh(20.0, 40.0)
+        );
+        assert_eq!(
+            filter.filter_bounds(
+                IRect::from_wh(10, 20),
+                &Matrix::default(),
+                MapDirection::Forward,
+                None
+            ),
+            IRect::from_wh(20, 40)
+        );
+
+        // The left red half is scaled to cover the whole surface.
+        let mut scaled = draw_filtered(Some(filter));
+        let pixmap = scaled.peek_pixels().unwrap();
+        assert_eq!(pixmap.get_color((30, 20)), Color::RED);
+    }
 }
@@ -7,7 +7,7 @@ use sb::SkRuntimeEffect_Child;
 use skia_bindings::{
     self as sb, SkRefCntBase, SkRuntimeEffect, SkRuntimeEffect_Options, SkRuntimeEffect_Uniform,
 };
-use std::{ffi::CStr, fmt};
+use std::{ffi::CStr, fmt, mem};
 
 pub type Uniform = Handle<SkRuntimeEffect_Uniform>;
 unsafe_send_sync!(Uniform);
@@ -55,6 +55,12 @@ impl Uniform {
     pub fn size_in_bytes(&self) -> usize {
         unsafe { self.native().sizeInBytes() }
     }
+
+    /// The number of `f32` values this uniform expects, or `None` if it is not a float based
+    /// uniform.
+    pub fn float_count(&self) -> Option<usize> {
+        uniform::float_components(self.ty()).map(|c| c * self.count().max(0) as usize)
+    }
 }
 
 pub mod uniform {
@@ -63,6 +69,20 @@ pub mod uniform {
     pub use sb::SkRuntimeEffect_Uniform_Type as Type;
     variant_name!(Type::Float2x2, type_naming);
 
+    /// Returns the number of `f32` components of a single element of a float based uniform type.
+    pub(crate) fn float_components(ty: Type) -> Option<usize> {
+        match ty {
+            Type::Float => Some(1),
+            Type::Float2 => Some(2),
+            Type::Float3 => Some(3),
+            Type::Float4 => Some(4),
+            Type::Float2x2 => Some(4),
+            Type::Float3x3 => Some(9),
+            Type::Float4x4 => Some(16),
+            _ => None,
+        }
+    }
+
     bitflags! {
         pub struct Flags : u32 {
             const ARRAY = sb::SkRuntimeEffect_Uniform_Flags_kArray_Flag as _;
@@ -145,6 +165,10 @@ impl fmt::Debug for RuntimeEffect {
 }
 
 impl RuntimeEffect {
+    /// Compiles `sksl` into a runtime effect that can be used as a color filter.
+    ///
+    /// On failure, returns the compiler's error messages. Each message is prefixed with the line
+    /// of `sksl` it refers to, for example `error: 2: unknown identifier 'x'`.
     pub fn make_for_color_filer<'a>(
         sksl: impl AsRef<str>,
         options: impl Into<Option<&'a Options>>,
@@ -162,6 +186,9 @@ impl RuntimeEffect {
         .ok_or_else(|| error.to_string())
     }
 
+    /// Compiles `sksl` into a runtime effect that can be used as a shader.
+    ///
+    /// On failure, returns the compiler's error messages, see [`Self::make_for_color_filer()`].
     pub fn make_for_shader<'a>(
         sksl: impl AsRef<str>,
         options: impl Into<Option<&'a Options>>,
@@ -285,4 +312,154 @@ impl RuntimeEffect {
     }
 }
 
-// TODO: wrap SkRuntimeEffectBuilder, SkRuntimeShaderBuilder
+/// A builder for [`Shader`]s that are created from a [`RuntimeEffect`].
+///
+/// Uniforms and children are set by name and are validated against the layout the
+/// [`RuntimeEffect`] reports.
+#[derive(Debug)]
+pub struct RuntimeShaderBuilder {
+    effect: RuntimeEffect,
+    uniforms: Vec<u8>,
+    children: Vec<Option<Shader>>,
+}
+
+impl RuntimeShaderBuilder {
+    pub fn new(effect: RuntimeEffect) -> Self {
+        let uniforms = vec![0; effect.uniform_size()];
+        let children = vec![None; effect.children().len()];
+        Self {
+            effect,
+            uniforms,
+            children,
+        }
+    }
+
+    pub fn effect(&self) -> &RuntimeEffect {
+        &self.effect
+    }
+
+    /// The uniform data as it is passed to the [`RuntimeEffect`].
+    pub fn uniforms(&self) -> &[u8] {
+        &self.uniforms
+    }
+
+    /// Sets the float based uniform `name` to `values`.
+    ///
+    /// Returns an error if the uniform does not exist, is not float based, or if the number
+    /// of values does not match the uniform's type and array count.
+    pub fn set_uniform_float(
+        &mut self,
+        name: impl AsRef<str>,
+        values: &[f32],
+    ) -> Result<(), String> {
+        let name = name.as_ref();
+        let uniform = self
+            .effect
+            .uniforms()
+            .iter()
+            .find(|u| u.name() == name)
+            .ok_or_else(|| format!("uniform '{}' not found", name))?;
+        let expected = uniform.float_count().ok_or_else(|| {
+            format!(
+                "uniform '{}' of type {:?} is not a float uniform",
+                name,
+                uniform.ty()
+            )
+        })?;
+        if values.len() != expected {
+            return Err(format!(
+                "uniform '{}' of type {:?} expects {} floats, but {} were provided",
+                name,
+                uniform.ty(),
+                expected,
+                values.len()
+            ));
+        }
+
+        let offset = uniform.offset();
+        let bytes = &mut self.uniforms[offset..offset + values.len() * mem::size_of::<f32>()];
+        for (chunk, value) in bytes.chunks_exact_mut(mem::size_of::<f32>()).zip(values) {
+            chunk.copy_from_slice(&value.to_ne_bytes());
+        }
+        Ok(())
+    }
+
+    /// Sets the child shader `name`.
+    ///
+    /// Returns an error if the child does not exist.
+    pub fn set_child(
+        &mut self,
+        name: impl AsRef<str>,
+        shader: impl Into<Shader>,
+    ) -> Result<(), String> {
+        let name = name.as_ref();
+        let index = self
+            .effect
+            .children()
+            .iter()
+            .find(|c| c.name() == name)
+            .map(|c| c.index())
+            .ok_or_else(|| format!("child '{}' not found", name))?;
+        self.children[index] = Some(shader.into());
+        Ok(())
+    }
+
+    /// Creates the [`Shader`].
+    ///
+    /// Returns `None` if not all children are set, or if the [`RuntimeEffect`] fails to create
+    /// the shader.
+    pub fn make_shader<'a>(
+        &self,
+        local_matrix: impl Into<Option<&'a Matrix>>,
+        is_opaque: bool,
+    ) -> Option<Shader> {
+        let children: Option<Vec<Shader>> = self.children.iter().cloned().collect();
+        self.effect.make_shader(
+            Data::new_copy(&self.uniforms),
+            children?,
+            local_matrix,
+            is_opaque,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RuntimeEffect, RuntimeShaderBuilder};
+
+    const SKSL: &str = "
+        uniform float2 u;
+        half4 main(float2 p) {
+            return half4(half2(u), 0, 1);
+        }";
+
+    #[test]
+    fn set_uniform_float_by_name() {
+        let effect = RuntimeEffect::make_for_shader(SKSL, None).unwrap();
+        assert_eq!(effect.uniforms().len(), 1);
+        assert_eq!(effect.uniforms()[0].float_count(), Some(2));
+
+        let mut builder = RuntimeShaderBuilder::new(effect);
+        builder.set_uniform_float("u", &[0.25, 0.5]).unwrap();
+        assert_eq!(builder.uniforms()[..4], 0.25f32.to_ne_bytes());
+        assert_eq!(builder.uniforms()[4..8], 0.5f32.to_ne_bytes());
+        assert!(builder.make_shader(None, true).is_some());
+    }
+
+    #[test]
+    fn set_uniform_float_rejects_mismatches() {
+        let effect = RuntimeEffect::make_for_shader(SKSL, None).unwrap();
+        let mut builder = RuntimeShaderBuilder::new(effect);
+        assert!(builder.set_uniform_float("u", &[1.0]).is_err());
+        assert!(builder.set_uniform_float("u", &[1.0, 2.0, 3.0]).is_err());
+        assert!(builder.set_uniform_float("v", &[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn compile_error_contains_line_number() {
+        let sksl = "half4 main(float2 p) {\n    return half4(x, 0, 0, 1);\n}";
+        let error = RuntimeEffect::make_for_shader(sksl, None).unwrap_err();
+        assert!(error.contains("2: "), "{}", error);
+        assert!(error.contains("'x'"), "{}", error);
+    }
+}
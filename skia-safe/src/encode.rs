@@ -0,0 +1,21 @@
+// TODO: wrap stream variants.
+
+pub mod jpeg_encoder;
+pub mod png_encoder;
+pub mod webp_encoder;
+
+use crate::prelude::*;
+use crate::{Bitmap, Data, EncodedImageFormat, Pixmap};
+use skia_bindings as sb;
+
+pub fn pixmap(src: &Pixmap, format: EncodedImageFormat, quality: usize) -> Option<Data> {
+    Data::from_ptr(unsafe {
+        sb::C_SkEncodePixmap(src.native(), format, quality.try_into().unwrap())
+    })
+}
+
+pub fn bitmap(src: &Bitmap, format: EncodedImageFormat, quality: usize) -> Option<Data> {
+    Data::from_ptr(unsafe {
+        sb::C_SkEncodeBitmap(src.native(), format, quality.try_into().unwrap())
+    })
+}
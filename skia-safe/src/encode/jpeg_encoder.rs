@@ -0,0 +1,93 @@
+use crate::{interop::DynamicMemoryWStream, prelude::*, Data, Pixmap};
+use skia_bindings as sb;
+
+/// Chroma subsampling applied to the U and V planes.
+///
+/// - `BothDirections`: 4:2:0, U and V are sampled at half the resolution in both directions.
+/// - `Horizontal`: 4:2:2, U and V are sampled at half the resolution horizontally.
+/// - `No`: 4:4:4, U and V are sampled at full resolution.
+pub use sb::SkJpegEncoder_Downsample as Downsample;
+variant_name!(Downsample::BothDirections, downsample_naming);
+
+pub use sb::SkJpegEncoder_AlphaOption as AlphaOption;
+variant_name!(AlphaOption::BlendOnBlack, alpha_option_naming);
+
+/// Options for [`encode()`].
+///
+/// The color profile of the [`Pixmap`]'s color space is embedded as an ICC profile. This version
+/// of Skia does not support embedding XMP metadata or a custom ICC profile.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Options {
+    /// Quality in the range `0..=100`, [`encode()`] fails for higher values.
+    pub quality: u32,
+    pub downsample: Downsample,
+    /// Specifies how to handle transparent pixels, JPEG does not support alpha.
+    pub alpha_option: AlphaOption,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            quality: 100,
+            downsample: Downsample::BothDirections,
+            alpha_option: AlphaOption::Ignore,
+        }
+    }
+}
+
+/// Encodes `pixmap` as a JPEG.
+///
+/// Returns `None` if the pixmap's color type is not supported or the quality is out of range.
+pub fn encode(pixmap: &Pixmap, options: &Options) -> Option<Data> {
+    if options.quality > 100 {
+        return None;
+    }
+    let mut stream = DynamicMemoryWStream::new();
+    unsafe {
+        sb::C_SkJpegEncoder_Encode(
+            stream.native_mut().base_mut(),
+            pixmap.native(),
+            options.quality as _,
+            options.downsample,
+            options.alpha_option,
+        )
+    }
+    .if_true_then_some(|| stream.detach_as_data())
+}
+
+#[test]
+fn downsample_changes_encoded_size() {
+    use crate::{AlphaType, ColorType, ImageInfo};
+
+    let info = ImageInfo::new((64, 64), ColorType::RGBA8888, AlphaType::Premul, None);
+    // A red / blue checkerboard that has lots of chroma detail.
+    let pixels: Vec<u8> = (0..64 * 64)
+        .flat_map(|i| {
+            if (i % 64 + i / 64) % 2 == 0 {
+                vec![0xff, 0, 0, 0xff]
+            } else {
+                vec![0, 0, 0xff, 0xff]
+            }
+        })
+        .collect();
+    let pixmap = Pixmap::new(&info, &pixels, info.min_row_bytes());
+
+    let encode_with = |downsample| {
+        let options = Options {
+            downsample,
+            ..Options::default()
+        };
+        encode(&pixmap, &options).unwrap()
+    };
+
+    let yuv420 = encode_with(Downsample::BothDirections);
+    let yuv444 = encode_with(Downsample::No);
+    assert!(yuv420.as_bytes().starts_with(&[0xff, 0xd8]));
+    assert_ne!(yuv420.size(), yuv444.size());
+
+    let out_of_range = Options {
+        quality: 101,
+        ..Options::default()
+    };
+    assert!(encode(&pixmap, &out_of_range).is_none());
+}
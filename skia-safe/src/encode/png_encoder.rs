@@ -0,0 +1,201 @@
+use crate::{interop::DynamicMemoryWStream, prelude::*, Data, DataTable, Pixmap};
+use skia_bindings as sb;
+use std::ffi::CString;
+
+bitflags! {
+    pub struct FilterFlag: u32 {
+        const ZERO = sb::SkPngEncoder_FilterFlag::kZero as _;
+        const NONE = sb::SkPngEncoder_FilterFlag::kNone as _;
+        const SUB = sb::SkPngEncoder_FilterFlag::kSub as _;
+        const UP = sb::SkPngEncoder_FilterFlag::kUp as _;
+        const AVG = sb::SkPngEncoder_FilterFlag::kAvg as _;
+        const PAETH = sb::SkPngEncoder_FilterFlag::kPaeth as _;
+        const ALL = sb::SkPngEncoder_FilterFlag::kAll as _;
+    }
+}
+
+impl Default for FilterFlag {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// A `tEXt` chunk that is written into the encoded PNG.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Comment {
+    pub keyword: String,
+    pub text: String,
+}
+
+impl Comment {
+    pub fn new(keyword: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            keyword: keyword.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// Options for [`encode()`].
+///
+/// The color profile is taken from the color space of the [`Pixmap`]: sRGB pixmaps are tagged
+/// with an `sRGB` chunk, all other color spaces are embedded as an `iCCP` chunk.
+///
+/// The bit depth is derived from the color type of the [`Pixmap`]: 16 bit color types like
+/// [`crate::ColorType::RGBAF16`] are encoded with 16 bits per channel, all others with 8 bits.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Options {
+    /// Selects the filtering strategies that are tried when encoding the rows.
+    pub filter_flags: FilterFlag,
+    /// zlib compression level in the range `0..=9`, [`encode()`] fails for other values.
+    pub z_lib_level: i32,
+    /// Text chunks to include in the PNG.
+    pub comments: Vec<Comment>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            filter_flags: FilterFlag::default(),
+            z_lib_level: 6,
+            comments: Vec::new(),
+        }
+    }
+}
+
+impl Options {
+    /// Returns `Some(None)` if there are no comments, and `None` if a comment contains a NUL
+    /// byte.
+    fn comments_to_data_table(&self) -> Option<Option<DataTable>> {
+        if self.comments.is_empty() {
+            return Some(None);
+        }
+        let strings = self
+            .comments
+            .iter()
+            .flat_map(|c| vec![&c.keyword, &c.text])
+            .map(|s| CString::new(s.as_str()).ok())
+            .collect::<Option<Vec<CString>>>()?;
+        let slices: Vec<&[u8]> = strings.iter().map(|s| s.as_bytes_with_nul()).collect();
+        Some(Some(DataTable::from_slices(&slices)))
+    }
+}
+
+/// Encodes `pixmap` as a PNG.
+///
+/// Returns `None` if the pixmap's color type is not supported or the options are invalid, for
+/// example if the zlib level is out of range or a comment contains a NUL byte.
+pub fn encode(pixmap: &Pixmap, options: &Options) -> Option<Data> {
+    if !(0..=9).contains(&options.z_lib_level) {
+        return None;
+    }
+    let comments = options.comments_to_data_table()?;
+    let mut stream = DynamicMemoryWStream::new();
+    unsafe {
+        sb::C_SkPngEncoder_Encode(
+            stream.native_mut().base_mut(),
+            pixmap.native(),
+            comments.as_ref().native_ptr_or_null(),
+            options.filter_flags.bits() as _,
+            options.z_lib_level,
+        )
+    }
+    .if_true_then_some(|| stream.detach_as_data())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode, Comment, Options};
+    use crate::{AlphaType, ColorSpace, ColorType, ImageInfo, Pixmap};
+
+    fn encode_2x2(color_space: Option<ColorSpace>, options: &Options) -> Vec<u8> {
+        let info = ImageInfo::new((2, 2), ColorType::RGBA8888, AlphaType::Premul, color_space);
+        let pixels = [0xffu8; 16];
+        let pixmap = Pixmap::new(&info, &pixels, info.min_row_bytes());
+        encode(&pixmap, options).unwrap().as_bytes().to_vec()
+    }
+
+    fn contains_chunk(png: &[u8], name: &[u8; 4]) -> bool {
+        png.windows(4).any(|w| w == name)
+    }
+
+    #[test]
+    fn embeds_color_profile() {
+        let png = encode_2x2(Some(ColorSpace::new_srgb()), &Options::default());
+        assert!(png.starts_with(b"\x89PNG"));
+        assert!(contains_chunk(&png, b"iCCP") || contains_chunk(&png, b"sRGB"));
+    }
+
+    #[test]
+    fn embeds_comments() {
+        let options = Options {
+            comments: vec![Comment::new("Author", "rust-skia")],
+            ..Options::default()
+        };
+        let png = encode_2x2(None, &options);
+        assert!(contains_chunk(&png, b"tEXt"));
+        assert!(png.windows(9).any(|w| w == b"rust-skia"));
+    }
+
+    #[test]
+    fn invalid_options_fail() {
+        let info = ImageInfo::new((2, 2), ColorType::RGBA8888, AlphaType::Premul, None);
+        let pixels = [0xffu8; 16];
+        let pixmap = Pixmap::new(&info, &pixels, info.min_row_bytes());
+
+        let z_lib_level_out_of_range = Options {
+            z_lib_level: 10,
+            ..Options::default()
+        };
+        assert!(encode(&pixmap, &z_lib_level_out_of_range).is_none());
+
+        let comment_with_nul = Options {
+            comments: vec![Comment::new("Author", "rust\0skia")],
+            ..Options::default()
+        };
+        assert!(encode(&pixmap, &comment_with_nul).is_none());
+    }
+
+    #[test]
+    fn image_encode_png() {
+        let mut surface = crate::Surface::new_raster_n32_premul((16, 16)).unwrap();
+        surface.canvas().clear(crate::Color::BLUE);
+        let image = surface.image_snapshot();
+
+        let stored = image
+            .encode_png(&Options {
+                z_lib_level: 0,
+                ..Options::default()
+            })
+            .unwrap();
+        let compressed = image
+            .encode_png(&Options {
+                z_lib_level: 9,
+                ..Options::default()
+            })
+            .unwrap();
+        assert!(compressed.as_bytes().starts_with(b"\x89PNG"));
+        assert!(compressed.size() <= stored.size());
+    }
+
+    #[test]
+    fn higher_z_lib_level_is_not_larger() {
+        let info = ImageInfo::new((64, 64), ColorType::RGBA8888, AlphaType::Premul, None);
+        let pixels: Vec<u8> = (0..64 * 64)
+            .flat_map(|i| vec![(i % 64 * 4) as u8, (i / 64 * 4) as u8, 0x80, 0xff])
+            .collect();
+        let pixmap = Pixmap::new(&info, &pixels, info.min_row_bytes());
+        let encode_with = |z_lib_level| {
+            let options = Options {
+                z_lib_level,
+                ..Options::default()
+            };
+            encode(&pixmap, &options).unwrap()
+        };
+
+        let stored = encode_with(0);
+        let compressed = encode_with(9);
+        assert!(compressed.size() <= stored.size());
+        assert!(compressed.size() < pixels.len());
+    }
+}
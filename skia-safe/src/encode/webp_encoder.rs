@@ -0,0 +1,138 @@
+use crate::{interop::DynamicMemoryWStream, prelude::*, Data, Pixmap};
+use skia_bindings::{self as sb, SkWebpEncoder_Compression};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum Compression {
+    Lossy = SkWebpEncoder_Compression::kLossy as _,
+    Lossless = SkWebpEncoder_Compression::kLossless as _,
+}
+
+native_transmutable!(SkWebpEncoder_Compression, Compression, compression_layout);
+
+impl Default for Compression {
+    fn default() -> Self {
+        Compression::Lossy
+    }
+}
+
+/// Options for [`encode()`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Options {
+    pub compression: Compression,
+    /// A value in the range `0.0..=100.0`.
+    ///
+    /// With [`Compression::Lossy`], this is the visual quality, lower values produce smaller
+    /// files, and [`encode()`] fails for values outside of the range. With
+    /// [`Compression::Lossless`], this is the effort spent on compressing, higher values produce
+    /// smaller files, but take longer to encode, and values outside of the range are clamped.
+    pub quality: f32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            compression: Compression::default(),
+            quality: 100.0,
+        }
+    }
+}
+
+impl Options {
+    pub fn lossy(quality: f32) -> Self {
+        Self {
+            compression: Compression::Lossy,
+            quality,
+        }
+    }
+
+    pub fn lossless() -> Self {
+        Self {
+            compression: Compression::Lossless,
+            ..Self::default()
+        }
+    }
+}
+
+/// Encodes `pixmap` as a WEBP.
+///
+/// Returns `None` if the pixmap's color type is not supported, if the lossy quality is out of
+/// range, or if the crate was built without the `webp-encode` feature.
+pub fn encode(pixmap: &Pixmap, options: &Options) -> Option<Data> {
+    let quality = match options.compression {
+        Compression::Lossy if !(0.0..=100.0).contains(&options.quality) => return None,
+        Compression::Lossy => options.quality,
+        Compression::Lossless => options.quality.max(0.0).min(100.0),
+    };
+    let mut stream = DynamicMemoryWStream::new();
+    unsafe {
+        sb::C_SkWebpEncoder_Encode(
+            stream.native_mut().base_mut(),
+            pixmap.native(),
+            options.compression.into_native(),
+            quality,
+        )
+    }
+    .if_true_then_some(|| stream.detach_as_data())
+}
+
+#[cfg(all(test, feature = "webp-encode", feature = "webp-decode"))]
+mod tests {
+    use super::{encode, Options};
+    use crate::{AlphaType, Color, ColorType, Image, ImageInfo, Pixmap};
+
+    fn gradient() -> (ImageInfo, Vec<u8>) {
+        let info = ImageInfo::new((64, 64), ColorType::RGBA8888, AlphaType::Premul, None);
+        let pixels = (0..64 * 64)
+            .flat_map(|i| vec![(i % 64 * 4) as u8, (i / 64 * 4) as u8, 0x80, 0xff])
+            .collect();
+        (info, pixels)
+    }
+
+    fn decoded_colors(data: crate::Data) -> Vec<Color> {
+        let image = Image::from_encoded(data)
+            .unwrap()
+            .new_raster_image()
+            .unwrap();
+        let pixmap = image.peek_pixels().unwrap();
+        (0..64 * 64)
+            .map(|i| pixmap.get_color((i % 64, i / 64)))
+            .collect()
+    }
+
+    #[test]
+    fn lossless_round_trips_and_lossy_does_not() {
+        let (info, pixels) = gradient();
+        let pixmap = Pixmap::new(&info, &pixels, info.min_row_bytes());
+        let original: Vec<Color> = (0..64 * 64)
+            .map(|i| pixmap.get_color((i % 64, i / 64)))
+            .collect();
+
+        let lossless = encode(&pixmap, &Options::lossless()).unwrap();
+        assert!(lossless.as_bytes().starts_with(b"RIFF"));
+        assert_eq!(decoded_colors(lossless), original);
+
+        let lossy = encode(&pixmap, &Options::lossy(10.0)).unwrap();
+        assert!(lossy.as_bytes().starts_with(b"RIFF"));
+        assert_ne!(decoded_colors(lossy), original);
+    }
+
+    #[test]
+    fn image_encode_webp() {
+        let (info, pixels) = gradient();
+        let image =
+            Image::from_raster_data(&info, crate::Data::new_copy(&pixels), info.min_row_bytes())
+                .unwrap();
+
+        // Lossless ignores the quality.
+        let lossless = image.encode_webp(true, 1000.0).unwrap();
+        let pixmap = image.peek_pixels().unwrap();
+        let original: Vec<Color> = (0..64 * 64)
+            .map(|i| pixmap.get_color((i % 64, i / 64)))
+            .collect();
+        assert_eq!(decoded_colors(lossless), original);
+
+        assert!(image.encode_webp(false, 50.0).is_some());
+        assert!(image.encode_webp(false, 101.0).is_none());
+    }
+}
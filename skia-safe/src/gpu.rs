@@ -1,6 +1,9 @@
 mod backend_drawable_info;
 pub use self::backend_drawable_info::*;
 
+mod backend_semaphore;
+pub use self::backend_semaphore::*;
+
 mod backend_surface;
 pub use self::backend_surface::*;
 
@@ -25,6 +28,8 @@ pub use self::driver_bug_workarounds::DriverBugWorkarounds;
 #[cfg(feature = "gl")]
 pub mod gl;
 
+pub mod mock;
+
 #[cfg(feature = "metal")]
 pub mod mtl;
 
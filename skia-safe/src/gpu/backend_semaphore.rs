@@ -0,0 +1,61 @@
+use crate::{gpu::BackendAPI, prelude::*};
+use skia_bindings::{self as sb, GrBackendSemaphore};
+use std::fmt;
+
+/// Wrapper for a semaphore of the 3D API in use.
+///
+/// Semaphores that are passed uninitialized as signal semaphores in a
+/// [`crate::gpu::FlushInfo`] are created and initialized by Skia.
+pub type BackendSemaphore = Handle<GrBackendSemaphore>;
+unsafe_send_sync!(BackendSemaphore);
+
+impl NativeDrop for GrBackendSemaphore {
+    fn drop(&mut self) {
+        unsafe { sb::C_GrBackendSemaphore_destruct(self) }
+    }
+}
+
+impl Default for BackendSemaphore {
+    fn default() -> Self {
+        BackendSemaphore::construct(|s| unsafe { sb::C_GrBackendSemaphore_Construct(s) })
+    }
+}
+
+impl fmt::Debug for BackendSemaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("BackendSemaphore");
+        d.field("is_initialized", &self.is_initialized());
+        if self.is_initialized() {
+            d.field("backend", &self.backend());
+        }
+        d.finish()
+    }
+}
+
+impl BackendSemaphore {
+    #[cfg(feature = "vulkan")]
+    pub fn new_vulkan(semaphore: crate::gpu::vk::Semaphore) -> Self {
+        let mut s = Self::default();
+        unsafe { sb::C_GrBackendSemaphore_initVulkan(s.native_mut(), semaphore) }
+        s
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        unsafe { sb::C_GrBackendSemaphore_isInitialized(self.native()) }
+    }
+
+    pub fn backend(&self) -> BackendAPI {
+        unsafe { sb::C_GrBackendSemaphore_backend(self.native()) }
+    }
+
+    #[cfg(feature = "vulkan")]
+    pub fn vk_semaphore(&self) -> Option<crate::gpu::vk::Semaphore> {
+        (self.is_initialized() && self.backend() == BackendAPI::Vulkan)
+            .if_true_then_some(|| unsafe { sb::C_GrBackendSemaphore_vkSemaphore(self.native()) })
+    }
+}
+
+#[test]
+fn default_is_not_initialized() {
+    assert!(!BackendSemaphore::default().is_initialized());
+}
@@ -417,6 +417,9 @@ impl fmt::Debug for BackendRenderTarget {
 }
 
 impl BackendRenderTarget {
+    /// Wraps an OpenGL framebuffer. `stencil_bits` is the number of bits of the framebuffer's
+    /// stencil attachment, or 0 if it has none, in which case Skia avoids stencil based path
+    /// rendering.
     #[cfg(feature = "gl")]
     pub fn new_gl(
         (width, height): (i32, i32),
@@ -574,3 +577,26 @@ impl BackendRenderTarget {
         rt.fIsValid
     }
 }
+
+#[cfg(all(test, feature = "gl"))]
+mod tests {
+    use super::BackendRenderTarget;
+    use crate::gpu::{gl, BackendAPI};
+
+    #[test]
+    fn gl_render_target_stencil_bits() {
+        const GL_RGBA8: gl::Enum = 0x8058;
+        let info = gl::FramebufferInfo {
+            fboid: 0,
+            format: GL_RGBA8,
+        };
+        let render_target = BackendRenderTarget::new_gl((16, 16), None, 8, info);
+        assert_eq!(render_target.backend(), BackendAPI::OpenGL);
+        assert_eq!(render_target.stencil_bits(), 8);
+        assert_eq!(render_target.sample_count(), 1);
+        assert_eq!(render_target.gl_framebuffer_info(), Some(info));
+
+        let render_target = BackendRenderTarget::new_gl((16, 16), None, 0, info);
+        assert_eq!(render_target.stencil_bits(), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{gpu::BackendAPI, prelude::*};
 use skia_bindings::{self as sb, GrBackendSurfaceMutableState};
 use std::fmt;
 
@@ -21,7 +21,10 @@ impl Default for BackendSurfaceMutableState {
 
 impl fmt::Debug for BackendSurfaceMutableState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("BackendSurfaceMutableState").finish()
+        f.debug_struct("BackendSurfaceMutableState")
+            .field("is_valid", &self.is_valid())
+            .field("backend", &self.backend())
+            .finish()
     }
 }
 
@@ -32,4 +35,43 @@ impl BackendSurfaceMutableState {
             sb::C_GrBackendSurfaceMutableState_ConstructVK(ptr, layout, queue_family_index)
         })
     }
+
+    pub fn is_valid(&self) -> bool {
+        unsafe { sb::C_GrBackendSurfaceMutableState_isValid(self.native()) }
+    }
+
+    pub fn backend(&self) -> BackendAPI {
+        unsafe { sb::C_GrBackendSurfaceMutableState_backend(self.native()) }
+    }
+
+    /// Returns the Vulkan image layout, or [`crate::gpu::vk::ImageLayout::UNDEFINED`] if the
+    /// state is not Vulkan backed.
+    #[cfg(feature = "vulkan")]
+    pub fn vk_image_layout(&self) -> crate::gpu::vk::ImageLayout {
+        unsafe { sb::C_GrBackendSurfaceMutableState_getVkImageLayout(self.native()) }
+    }
+
+    /// Returns the Vulkan queue family index, or `VK_QUEUE_FAMILY_IGNORED` if the state is not
+    /// Vulkan backed.
+    #[cfg(feature = "vulkan")]
+    pub fn queue_family_index(&self) -> u32 {
+        unsafe { sb::C_GrBackendSurfaceMutableState_getQueueFamilyIndex(self.native()) }
+    }
+}
+
+#[cfg(feature = "vulkan")]
+#[test]
+fn vk_state_round_trip() {
+    use crate::gpu::vk;
+
+    let state = BackendSurfaceMutableState::new_vk(vk::ImageLayout::PRESENT_SRC_KHR, 3);
+    assert!(state.is_valid());
+    assert_eq!(state.backend(), BackendAPI::Vulkan);
+    assert_eq!(state.vk_image_layout(), vk::ImageLayout::PRESENT_SRC_KHR);
+    assert_eq!(state.queue_family_index(), 3);
+}
+
+#[test]
+fn default_is_invalid() {
+    assert!(!BackendSurfaceMutableState::default().is_valid());
 }
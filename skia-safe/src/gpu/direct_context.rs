@@ -5,8 +5,8 @@ use super::gl;
 #[cfg(feature = "vulkan")]
 use super::vk;
 use super::{
-    BackendFormat, BackendRenderTarget, BackendSurfaceMutableState, BackendTexture, ContextOptions,
-    FlushInfo, RecordingContext, SemaphoresSubmitted,
+    BackendAPI, BackendFormat, BackendRenderTarget, BackendSemaphore, BackendSurfaceMutableState,
+    BackendTexture, ContextOptions, FlushInfo, RecordingContext, SemaphoresSubmitted,
 };
 use crate::{image, prelude::*, Data};
 use skia_bindings::{self as sb, GrDirectContext, GrDirectContext_DirectContextID, SkRefCntBase};
@@ -132,6 +132,32 @@ impl DirectContext {
         ))
     }
 
+    /// Creates a context that uses Skia's mock backend, which accepts all GPU work without
+    /// executing it. Useful for testing GPU code paths without a device.
+    pub fn new_mock<'a>(options: impl Into<Option<&'a ContextOptions>>) -> Option<DirectContext> {
+        DirectContext::from_ptr(unsafe {
+            sb::C_GrDirectContext_MakeMock(options.into().native_ptr_or_null())
+        })
+    }
+
+    /// Creates a context for `backend` with default backend parameters.
+    ///
+    /// Only backends that do not need any native handles are supported: [`BackendAPI::Mock`]
+    /// and, if the `gl` feature is enabled, [`BackendAPI::OpenGL`], which uses the GL context that
+    /// is current on the calling thread. Returns `None` for all other backends or if the context
+    /// can not be created, so callers can fall back to [`Self::new_mock()`].
+    pub fn new_for_backend<'a>(
+        backend: BackendAPI,
+        options: impl Into<Option<&'a ContextOptions>>,
+    ) -> Option<DirectContext> {
+        match backend {
+            BackendAPI::Mock => Self::new_mock(options),
+            #[cfg(feature = "gl")]
+            BackendAPI::OpenGL => Self::new_gl(None, options),
+            _ => None,
+        }
+    }
+
     pub fn reset(&mut self, backend_state: Option<u32>) -> &mut Self {
         unsafe {
             self.native_mut()
@@ -246,7 +272,22 @@ impl DirectContext {
         self
     }
 
-    // TODO: wait()
+    /// Makes the GPU wait on `semaphores` before executing any further work submitted to this
+    /// context. Returns `false` if the wait could not be inserted, in which case the client is
+    /// still responsible for the semaphores.
+    pub fn wait(
+        &mut self,
+        semaphores: &[BackendSemaphore],
+        delete_semaphores_after_wait: impl Into<Option<bool>>,
+    ) -> bool {
+        unsafe {
+            self.native_mut().wait(
+                semaphores.len().try_into().unwrap(),
+                semaphores.native().as_ptr(),
+                delete_semaphores_after_wait.into().unwrap_or(true),
+            )
+        }
+    }
 
     pub fn flush_and_submit(&mut self) -> &mut Self {
         unsafe { sb::C_GrDirectContext_flushAndSubmit(self.native_mut()) }
@@ -267,10 +308,10 @@ impl DirectContext {
     pub fn flush<'a>(&mut self, info: impl Into<Option<&'a FlushInfo>>) -> SemaphoresSubmitted {
         let n = self.native_mut();
         if let Some(info) = info.into() {
-            unsafe { n.flush(info.native()) }
+            info.flush_with(true, |info| unsafe { n.flush(info) })
         } else {
             let info = FlushInfo::default();
-            unsafe { n.flush(info.native()) }
+            info.flush_with(true, |info| unsafe { n.flush(info) })
         }
     }
 
@@ -278,6 +319,23 @@ impl DirectContext {
         unsafe { self.native_mut().submit(sync_cpu.into().unwrap_or(false)) }
     }
 
+    /// Flushes and submits all pending work and calls `finished` when the GPU has finished
+    /// executing it.
+    ///
+    /// `finished` is called from [`Self::check_async_work_completion()`], a later synchronizing
+    /// submit, or when the context is abandoned, and is always called exactly once, even if the
+    /// flush fails.
+    pub fn submit_with_finished_proc(
+        &mut self,
+        sync_cpu: impl Into<Option<bool>>,
+        finished: impl FnOnce() + Send + 'static,
+    ) -> bool {
+        let mut info = FlushInfo::default();
+        info.set_finished_proc(finished);
+        self.flush(&info);
+        self.submit(sync_cpu)
+    }
+
     pub fn check_async_work_completion(&mut self) {
         unsafe { self.native_mut().checkAsyncWorkCompletion() }
     }
@@ -393,3 +451,43 @@ impl DirectContext {
         id
     }
 }
+
+#[test]
+fn create_mock_context_for_backend() {
+    let context = DirectContext::new_for_backend(BackendAPI::Mock, None).unwrap();
+    assert_eq!(context.backend(), BackendAPI::Mock);
+    assert!(context.max_texture_size() > 0);
+
+    assert!(DirectContext::new_for_backend(BackendAPI::Dawn, None).is_none());
+}
+
+#[test]
+fn submit_with_finished_proc() {
+    use crate::{AlphaType, Budgeted, ColorType, ImageInfo, Surface};
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    let mut context = DirectContext::new_mock(None).unwrap();
+    let info = ImageInfo::new((16, 16), ColorType::RGBA8888, AlphaType::Premul, None);
+    let mut surface = Surface::new_render_target(
+        &mut context,
+        Budgeted::Yes,
+        &info,
+        None,
+        super::SurfaceOrigin::TopLeft,
+        None,
+        None,
+    )
+    .unwrap();
+    surface.canvas().clear(crate::Color::GREEN);
+
+    let finished = Arc::new(AtomicBool::new(false));
+    let finished_in_proc = finished.clone();
+    assert!(context.submit_with_finished_proc(false, move || {
+        finished_in_proc.store(true, Ordering::SeqCst)
+    }));
+    context.flush_submit_and_sync_cpu();
+    assert!(finished.load(Ordering::SeqCst));
+}
@@ -4,5 +4,8 @@ pub use extensions::*;
 mod interface;
 pub use interface::*;
 
+mod surface;
+pub use surface::*;
+
 mod types;
 pub use types::*;
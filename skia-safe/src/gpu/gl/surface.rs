@@ -0,0 +1,42 @@
+use super::TextureInfo;
+use crate::{
+    gpu::{BackendTexture, Mipmapped, RecordingContext, SurfaceOrigin},
+    ColorType, ISize, Surface, SurfaceProps,
+};
+
+/// Wraps an existing GL texture as a renderable [`Surface`].
+///
+/// This is a shortcut for creating a [`BackendTexture`] with [`BackendTexture::new_gl()`] and
+/// passing it to [`Surface::from_backend_texture()`]. The texture is not owned by the surface
+/// and must outlive it.
+///
+/// Returns `None` if the texture's format is not renderable with `color_type`, or if
+/// `sample_count` is not supported.
+///
+/// # Safety
+///
+/// `texture_info` must describe a valid GL texture of the given `size` that belongs to the
+/// GL context `context` was created from.
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn wrap_texture_as_surface(
+    context: &mut RecordingContext,
+    texture_info: TextureInfo,
+    size: impl Into<ISize>,
+    sample_count: impl Into<Option<usize>>,
+    color_type: ColorType,
+    origin: SurfaceOrigin,
+    surface_props: Option<&SurfaceProps>,
+) -> Option<Surface> {
+    let size = size.into();
+    let backend_texture =
+        BackendTexture::new_gl((size.width, size.height), Mipmapped::No, texture_info);
+    Surface::from_backend_texture(
+        context,
+        &backend_texture,
+        origin,
+        sample_count,
+        color_type,
+        None,
+        surface_props,
+    )
+}
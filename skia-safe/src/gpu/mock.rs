@@ -0,0 +1,57 @@
+//! Skia's mock GPU backend.
+//!
+//! A mock context accepts all GPU work, but does not execute it. It can be used to exercise GPU
+//! code paths like surface creation, texture uploads, and flushing on machines without a GPU.
+//! Pixels rendered by a mock context are undefined.
+
+use super::{ContextOptions, DirectContext};
+
+/// Creates a [`DirectContext`] that uses the mock backend.
+pub fn make_context<'a>(options: impl Into<Option<&'a ContextOptions>>) -> Option<DirectContext> {
+    DirectContext::new_mock(options)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{gpu, AlphaType, Budgeted, ColorType, ImageInfo, Surface};
+
+    #[test]
+    fn render_to_mock_surface_and_flush() {
+        let mut context = super::make_context(None).unwrap();
+        assert_eq!(context.backend(), gpu::BackendAPI::Mock);
+
+        let info = ImageInfo::new((64, 64), ColorType::RGBA8888, AlphaType::Premul, None);
+        let mut surface = Surface::new_render_target(
+            &mut context,
+            Budgeted::Yes,
+            &info,
+            None,
+            gpu::SurfaceOrigin::TopLeft,
+            None,
+            None,
+        )
+        .unwrap();
+
+        surface.canvas().clear(crate::Color::RED);
+
+        let image = surface.image_snapshot();
+        assert!(image.is_texture_backed());
+
+        surface.flush_and_submit();
+        context.flush_and_submit();
+    }
+
+    #[test]
+    fn upload_raster_image_to_mock_context() {
+        let mut context = super::make_context(None).unwrap();
+        let mut raster = Surface::new_raster_n32_premul((16, 16)).unwrap();
+        raster.canvas().clear(crate::Color::BLUE);
+        let image = raster.image_snapshot();
+
+        let texture = image
+            .new_texture_image(&mut context, gpu::Mipmapped::No)
+            .unwrap();
+        assert!(texture.is_texture_backed());
+        assert_eq!(texture.dimensions(), image.dimensions());
+    }
+}
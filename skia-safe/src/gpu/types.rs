@@ -1,5 +1,11 @@
 use skia_bindings as sb;
-use std::ptr;
+use std::{
+    convert::TryInto,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ptr,
+    sync::{Arc, Mutex},
+};
 
 pub use skia_bindings::GrBackendApi as BackendAPI;
 variant_name!(BackendAPI::Dawn, backend_api_naming);
@@ -27,20 +33,25 @@ variant_name!(SurfaceOrigin::BottomLeft, surface_origin_naming);
 
 // Note: BackendState is in gl/types.rs/
 
-#[repr(C)]
-#[allow(dead_code)]
+/// Describes how to flush pending GPU work.
+///
+/// The info borrows the signal semaphores it was given for `'a`, and owns the finished proc
+/// until it is dropped.
+// Not transmutable to a GrFlushInfo, because it owns a reference to the finished proc. Flushes go
+// through flush_with() instead, which hands Skia its own reference.
 #[derive(Debug)]
-pub struct FlushInfo {
-    // TODO: wrap access to the following fields in a safe way:
+pub struct FlushInfo<'a> {
     num_semaphores: std::os::raw::c_int,
     signal_semaphores: *mut sb::GrBackendSemaphore,
     finished_proc: sb::GrGpuFinishedProc,
     finished_context: sb::GrGpuFinishedContext,
+    // TODO: wrap the submitted proc.
     submitted_proc: sb::GrGpuSubmittedProc,
     submitted_context: sb::GrGpuSubmittedContext,
+    pd: PhantomData<&'a mut [super::BackendSemaphore]>,
 }
 
-impl Default for FlushInfo {
+impl Default for FlushInfo<'_> {
     fn default() -> Self {
         Self {
             num_semaphores: 0,
@@ -49,13 +60,214 @@ impl Default for FlushInfo {
             finished_context: ptr::null_mut(),
             submitted_proc: None,
             submitted_context: ptr::null_mut(),
+            pd: PhantomData,
         }
     }
 }
 
-native_transmutable!(sb::GrFlushInfo, FlushInfo, flush_info_layout);
+impl Drop for FlushInfo<'_> {
+    fn drop(&mut self) {
+        self.release_finished_proc()
+    }
+}
+
+impl<'a> FlushInfo<'a> {
+    /// Sets the semaphores Skia signals when the GPU work of the flush is finished.
+    /// Uninitialized semaphores are created by Skia and can be inspected after the flush, once
+    /// the info is dropped.
+    pub fn set_signal_semaphores(
+        &mut self,
+        semaphores: &'a mut [super::BackendSemaphore],
+    ) -> &mut Self {
+        self.num_semaphores = semaphores.len().try_into().unwrap();
+        self.signal_semaphores = semaphores.as_mut_ptr() as _;
+        self
+    }
+
+    pub fn num_semaphores(&self) -> usize {
+        self.num_semaphores.try_into().unwrap()
+    }
+
+    /// Sets a function that is called when the GPU work of the flush is finished, even if the
+    /// flush fails.
+    ///
+    /// If the info is used for more than one flush, `finished` is called only once, when the
+    /// first of them has finished. If the info is dropped without being flushed, `finished` is
+    /// dropped without being called.
+    pub fn set_finished_proc(&mut self, finished: impl FnOnce() + Send + 'static) -> &mut Self {
+        self.release_finished_proc();
+        let finished: Box<dyn FnOnce() + Send> = Box::new(finished);
+        let finished: Arc<FinishedCallback> = Arc::new(Mutex::new(Some(finished)));
+        self.finished_proc = Some(call_finished_proc);
+        self.finished_context = Arc::into_raw(finished) as _;
+        self
+    }
+
+    pub fn has_finished_proc(&self) -> bool {
+        self.finished_proc.is_some()
+    }
+
+    /// Invokes `flush` with a native info that can be passed to Skia.
+    ///
+    /// If `calls_finished_proc` is `true`, the native info holds its own reference to the finished
+    /// proc, which Skia releases by calling the proc. Otherwise, the native flush ignores the
+    /// finished proc, so it is called right after `flush` returns.
+    pub(crate) fn flush_with<R>(
+        &self,
+        calls_finished_proc: bool,
+        flush: impl FnOnce(&sb::GrFlushInfo) -> R,
+    ) -> R {
+        let mut native = sb::GrFlushInfo {
+            fNumSemaphores: self.num_semaphores,
+            fSignalSemaphores: self.signal_semaphores,
+            fFinishedProc: None,
+            fFinishedContext: ptr::null_mut(),
+            fSubmittedProc: self.submitted_proc,
+            fSubmittedContext: self.submitted_context,
+        };
+        let finished = self.finished_callback();
+        if calls_finished_proc {
+            if let Some(finished) = finished {
+                native.fFinishedProc = self.finished_proc;
+                native.fFinishedContext = Arc::into_raw(finished) as _;
+            }
+            flush(&native)
+        } else {
+            let r = flush(&native);
+            if let Some(finished) = finished {
+                unsafe { call_finished_proc(Arc::into_raw(finished) as _) }
+            }
+            r
+        }
+    }
+
+    /// Releases the reference the info holds to the finished proc.
+    fn release_finished_proc(&mut self) {
+        if !self.finished_context.is_null() {
+            drop(unsafe { Arc::from_raw(self.finished_context as *const FinishedCallback) });
+            self.finished_proc = None;
+            self.finished_context = ptr::null_mut();
+        }
+    }
+
+    /// Returns a new reference to the finished proc.
+    fn finished_callback(&self) -> Option<Arc<FinishedCallback>> {
+        if self.finished_context.is_null() {
+            return None;
+        }
+        let finished = ManuallyDrop::new(unsafe {
+            Arc::from_raw(self.finished_context as *const FinishedCallback)
+        });
+        Some(Arc::clone(&finished))
+    }
+}
+
+type FinishedCallback = Mutex<Option<Box<dyn FnOnce() + Send>>>;
+
+unsafe extern "C" fn call_finished_proc(context: sb::GrGpuFinishedContext) {
+    let finished = Arc::from_raw(context as *const FinishedCallback);
+    let finished = finished
+        .lock()
+        .ok()
+        .and_then(|mut finished| finished.take());
+    if let Some(finished) = finished {
+        finished()
+    }
+}
 
 pub use sb::GrSemaphoresSubmitted as SemaphoresSubmitted;
 variant_name!(SemaphoresSubmitted::Yes, semaphores_submitted_naming);
 
 // TODO: wrap GrPrepareForExternalIORequests
+
+#[test]
+fn flush_raster_surface_with_signal_semaphores() {
+    let mut semaphores = [
+        super::BackendSemaphore::default(),
+        super::BackendSemaphore::default(),
+    ];
+    let mut info = FlushInfo::default();
+    info.set_signal_semaphores(&mut semaphores);
+    assert_eq!(info.num_semaphores(), 2);
+
+    // Raster surfaces have no GPU work to signal.
+    let mut surface = crate::Surface::new_raster_n32_premul((4, 4)).unwrap();
+    assert_eq!(
+        surface.flush_with_mutable_state(&info, None),
+        SemaphoresSubmitted::No
+    );
+    drop(info);
+    assert!(!semaphores[0].is_initialized());
+}
+
+#[cfg(test)]
+mod finished_proc_tests {
+    use super::FlushInfo;
+    use crate::gpu::{BackendSemaphore, DirectContext};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    fn counting_info<'a>(count: &Arc<AtomicUsize>) -> FlushInfo<'a> {
+        let count = count.clone();
+        let mut info = FlushInfo::default();
+        info.set_finished_proc(move || {
+            count.fetch_add(1, Ordering::SeqCst);
+        });
+        info
+    }
+
+    #[test]
+    fn flush_mock_context_with_signal_semaphore_and_finished_proc() {
+        let mut semaphores = [BackendSemaphore::default()];
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut info = counting_info(&count);
+        assert!(info.has_finished_proc());
+        info.set_signal_semaphores(&mut semaphores);
+        assert_eq!(info.num_semaphores(), 1);
+
+        let mut context = DirectContext::new_mock(None).unwrap();
+        context.flush(&info);
+        context.submit(true);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn finished_proc_is_called_once() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let info = counting_info(&count);
+        let mut context = DirectContext::new_mock(None).unwrap();
+        context.flush(&info);
+        context.flush(&info);
+        context.submit(true);
+        drop(info);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        // All references to the closure are released.
+        assert_eq!(Arc::strong_count(&count), 1);
+    }
+
+    #[test]
+    fn finished_proc_of_a_raster_flush_is_called_immediately() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let info = counting_info(&count);
+        let mut surface = crate::Surface::new_raster_n32_premul((4, 4)).unwrap();
+        surface.flush_with_mutable_state(&info, None);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn unflushed_finished_proc_is_dropped() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut info = counting_info(&count);
+        assert!(!FlushInfo::default().has_finished_proc());
+        // Replacing the proc releases the previous one.
+        info.set_finished_proc(|| {});
+        assert_eq!(Arc::strong_count(&count), 1);
+
+        let info = counting_info(&count);
+        drop(info);
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        assert_eq!(Arc::strong_count(&count), 1);
+    }
+}
@@ -1,4 +1,7 @@
-use super::{Device, GetProc, GetProcOf, Instance, PhysicalDevice, Queue, Version};
+use super::{
+    Device, GetProc, GetProcOf, Instance, MemoryAllocator, PhysicalDevice, PhysicalDeviceFeatures,
+    PhysicalDeviceFeatures2, Queue, Version,
+};
 use crate::{gpu, prelude::*};
 use ffi::CString;
 use raw::c_char;
@@ -143,6 +146,33 @@ impl BackendContext<'_> {
         self
     }
 
+    /// Sets the allocator Skia uses for all its Vulkan memory allocations. If not set, Skia
+    /// allocates memory internally.
+    ///
+    /// # Safety
+    /// The reference held by `memory_allocator` is moved into the `sk_sp` that is created in
+    /// `C_GrVkBackendContext_setMemoryAllocator`, and is released when the `BackendContext` is
+    /// dropped or another allocator is set.
+    pub fn set_memory_allocator(
+        &mut self,
+        memory_allocator: impl Into<Option<MemoryAllocator>>,
+    ) -> &mut Self {
+        unsafe {
+            sb::C_GrVkBackendContext_setMemoryAllocator(
+                self.native.as_ptr() as _,
+                memory_allocator.into().into_ptr_or_null(),
+            )
+        }
+        self
+    }
+
+    /// Returns the allocator set with [`Self::set_memory_allocator()`].
+    pub fn memory_allocator(&self) -> Option<MemoryAllocator> {
+        MemoryAllocator::from_unshared_ptr(unsafe {
+            sb::C_GrVkBackendContext_memoryAllocator(self.native.as_ptr() as _)
+        })
+    }
+
     pub(crate) unsafe fn begin_resolving(&self) -> impl Drop {
         Self::begin_resolving_proc(self.get_proc)
     }
@@ -162,6 +192,30 @@ impl BackendContext<'_> {
     }
 }
 
+impl<'a> BackendContext<'a> {
+    /// Tells Skia which features of the physical device are enabled on the device.
+    ///
+    /// Skia only uses the features that are set here, so this should match the features the
+    /// device was created with. Replaces features set with [`Self::set_device_features_2()`].
+    pub fn set_device_features(&mut self, features: &'a PhysicalDeviceFeatures) -> &mut Self {
+        unsafe { sb::C_GrVkBackendContext_setDeviceFeatures(self.native.as_ptr() as _, features) }
+        self
+    }
+
+    /// Like [`Self::set_device_features()`], but accepts a `VkPhysicalDeviceFeatures2` structure,
+    /// which may chain extension feature structures through its `pNext` pointer.
+    ///
+    /// # Safety
+    /// The `pNext` chain of `features` must be valid and must outlive the `BackendContext`.
+    pub unsafe fn set_device_features_2(
+        &mut self,
+        features: &'a PhysicalDeviceFeatures2,
+    ) -> &mut Self {
+        sb::C_GrVkBackendContext_setDeviceFeatures2(self.native.as_ptr() as _, features);
+        self
+    }
+}
+
 struct EndResolving {}
 
 impl Drop for EndResolving {
@@ -205,3 +259,64 @@ unsafe extern "C" fn global_get_proc(
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BackendContext;
+    use crate::{
+        gpu::vk::{GetProcOf, GetProcResult, MemoryAllocator},
+        prelude::*,
+    };
+    use skia_bindings::GrVkMemoryAllocator;
+    use std::{mem, ptr};
+
+    fn get_proc(_: GetProcOf) -> GetProcResult {
+        ptr::null()
+    }
+
+    // A context without Vulkan handles. With a null instance, Skia does not resolve any
+    // functions while creating it.
+    fn backend_context() -> BackendContext<'static> {
+        unsafe {
+            BackendContext::new(
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                (ptr::null_mut(), 0),
+                &get_proc,
+            )
+        }
+    }
+
+    #[test]
+    fn none_memory_allocator_round_trips() {
+        let mut context = backend_context();
+        assert!(context.memory_allocator().is_none());
+        context.set_memory_allocator(None);
+        assert!(context.memory_allocator().is_none());
+    }
+
+    #[test]
+    fn dropping_backend_context_releases_memory_allocator() {
+        // Never used for allocations. The test keeps one reference, so the reference count never
+        // drops to zero and the allocator's destructor is never called.
+        let native: *mut GrVkMemoryAllocator = Box::into_raw(Box::new(unsafe { mem::zeroed() }));
+        let ref_cnt = || unsafe { &*native }._ref_cnt();
+        unsafe { &*native }._ref();
+
+        unsafe { &*native }._ref();
+        let allocator = unsafe { MemoryAllocator::from_native_ptr(native) }.unwrap();
+        assert_eq!(ref_cnt(), 2);
+
+        let mut context = backend_context();
+        context.set_memory_allocator(allocator);
+        assert_eq!(ref_cnt(), 2);
+        assert!(context.memory_allocator().is_some());
+        assert_eq!(ref_cnt(), 2);
+
+        drop(context);
+        assert_eq!(ref_cnt(), 1);
+
+        drop(unsafe { Box::from_raw(native) });
+    }
+}
@@ -3,10 +3,10 @@ use crate::{
     prelude::*,
 };
 use skia_bindings::{
-    self as sb, GrVkAlloc, GrVkBackendMemory, GrVkDrawableInfo, GrVkImageInfo,
-    GrVkYcbcrConversionInfo,
+    self as sb, GrVkAlloc, GrVkBackendMemory, GrVkDrawableInfo, GrVkImageInfo, GrVkMemoryAllocator,
+    GrVkYcbcrConversionInfo, SkRefCntBase,
 };
-use std::{ffi::CStr, os::raw, ptr};
+use std::{ffi::CStr, fmt, os::raw, ptr};
 
 pub type GraphicsBackendMemory = GrVkBackendMemory;
 
@@ -70,6 +70,34 @@ impl Alloc {
     }
 }
 
+// TODO: support the implementation of custom Vulkan memory allocators in Rust.
+pub type MemoryAllocator = RCHandle<GrVkMemoryAllocator>;
+unsafe_send_sync!(MemoryAllocator);
+
+impl NativeRefCountedBase for GrVkMemoryAllocator {
+    type Base = SkRefCntBase;
+}
+
+impl fmt::Debug for MemoryAllocator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryAllocator").finish()
+    }
+}
+
+impl MemoryAllocator {
+    /// Wraps a `GrVkMemoryAllocator` that was created outside of Skia, for example one that
+    /// forwards to an application's own allocator.
+    ///
+    /// Returns `None` if `allocator` is `null`.
+    ///
+    /// # Safety
+    /// `allocator` must point to a valid `GrVkMemoryAllocator`. The reference the caller holds
+    /// is taken over by the returned `MemoryAllocator`.
+    pub unsafe fn from_native_ptr(allocator: *mut GrVkMemoryAllocator) -> Option<Self> {
+        Self::from_ptr(allocator)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
 pub struct YcbcrConversionInfo {
@@ -11,6 +11,7 @@ pub use codec::{Codec, EncodedOrigin};
 mod core;
 mod docs;
 mod effects;
+pub mod encode;
 #[cfg(feature = "gpu")]
 pub mod gpu;
 mod interop;
@@ -111,3 +111,25 @@ fn test_intersect() {
     let expected: Rect = Rect::from_point_and_size((15.0, 15.0), (5.0, 5.0));
     assert_eq!(intersected.tight_bounds().unwrap(), expected);
 }
+
+#[test]
+fn test_op_builder_union_of_overlapping_circles() {
+    let mut builder = OpBuilder::default();
+    for x in &[10.0, 20.0, 30.0] {
+        let mut circle = Path::new();
+        circle.add_circle((*x, 10.0), 8.0, None);
+        builder.add(&circle, PathOp::Union);
+    }
+    let union = builder.resolve().unwrap();
+
+    let contours = crate::ContourMeasureIter::new(&union, true, None).count();
+    assert_eq!(contours, 1);
+    let bounds = union.tight_bounds().unwrap();
+    let expected = [2.0, 2.0, 38.0, 18.0];
+    for (v, e) in [bounds.left, bounds.top, bounds.right, bounds.bottom]
+        .iter()
+        .zip(&expected)
+    {
+        assert!((v - e).abs() < 0.01);
+    }
+}
@@ -67,3 +67,36 @@ impl Path {
         get_path(text, p, font)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Align;
+    use crate::{Color, Font, Paint, Surface};
+
+    #[test]
+    fn draw_str_centered() {
+        let mut surface = Surface::new_raster_n32_premul((120, 40)).unwrap();
+        let font = Font::default().with_size(20.0).unwrap();
+        let mut paint = Paint::default();
+        paint.set_color(Color::BLACK);
+        surface.canvas().clear(Color::WHITE).draw_str_align(
+            "MMMM",
+            (60, 30),
+            &font,
+            &paint,
+            Align::Center,
+        );
+
+        let pixmap = surface.peek_pixels().unwrap();
+        let columns: Vec<i32> = (0..120)
+            .filter(|x| (0..40).any(|y| pixmap.get_color((*x, y)) != Color::WHITE))
+            .collect();
+        let (left, right) = match (columns.first(), columns.last()) {
+            (Some(left), Some(right)) => (*left, *right),
+            // no default typeface available.
+            _ => return,
+        };
+        assert!(left < 60 && right > 60);
+        assert!(((left + right) / 2 - 60).abs() <= 3);
+    }
+}
@@ -65,6 +65,53 @@ fn test_supported_decoders() {
     assert_eq!(supported, supported_decoders());
 }
 
+/// A 1x1 GIF with a black and a white frame, each shown for 100ms.
+const TWO_FRAME_GIF: &[u8] = &[
+    // header, logical screen descriptor, and a black and white global color table.
+    b'G', b'I', b'F', b'8', b'9', b'a', 1, 0, 1, 0, 0x80, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff,
+    // frame 1: graphic control extension with a delay of 10/100s, image descriptor, pixel 0.
+    0x21, 0xf9, 4, 0, 10, 0, 0, 0, 0x2c, 0, 0, 0, 0, 1, 0, 1, 0, 0, 2, 2, 0x44, 0x01, 0,
+    // frame 2: pixel 1.
+    0x21, 0xf9, 4, 0, 10, 0, 0, 0, 0x2c, 0, 0, 0, 0, 1, 0, 1, 0, 0, 2, 2, 0x4c, 0x01, 0,
+    // trailer
+    0x3b,
+];
+
+#[test]
+fn test_gif_frame_infos() {
+    use skia_safe::{Color, IRect};
+    use std::time::Duration;
+
+    let mut codec = codec::Codec::from_data(Data::new_copy(TWO_FRAME_GIF)).unwrap();
+    assert_eq!(codec.get_frame_count(), 2);
+
+    let frame_infos = codec.get_frame_infos();
+    assert_eq!(frame_infos.len(), 2);
+    for frame_info in &frame_infos {
+        assert_eq!(frame_info.duration, Duration::from_millis(100));
+        assert!(frame_info.fully_received);
+        assert_eq!(frame_info.frame_rect, IRect::from_wh(1, 1));
+        // both frames cover the whole image, so they can be decoded independently.
+        assert_eq!(frame_info.required_frame, None);
+    }
+    assert!(codec.get_frame_info(2).is_none());
+
+    let colors: Vec<Color> = (0..2)
+        .map(|frame_index| {
+            let options = codec::Options {
+                zero_initialized: codec::ZeroInitialized::No,
+                subset: None,
+                frame_index,
+                // ignored, because the frames do not depend on prior frames.
+                prior_frame: 0,
+            };
+            let image = codec.get_image(None, &options).unwrap();
+            image.peek_pixels().unwrap().get_color((0, 0))
+        })
+        .collect();
+    assert_eq!(colors, [Color::BLACK, Color::WHITE]);
+}
+
 type DecoderTest = (EncodedImageFormat, &'static [u8]);
 
 // image files copied from skia/resources/images
@@ -136,3 +183,73 @@ const ALL: &[EncodedImageFormat] = &[
     EncodedImageFormat::HEIF,
     EncodedImageFormat::AVIF,
 ];
+
+#[test]
+fn test_decode_png_matches_image_from_encoded() {
+    use skia_safe::{AlphaType, ColorType, Image, ImageInfo};
+
+    let bytes: &[u8] = include_bytes!("images/mandrill_16.png");
+    let mut codec = codec::Codec::from_data(Data::new_copy(bytes)).unwrap();
+    let info = ImageInfo::new(
+        codec.dimensions(),
+        ColorType::RGBA8888,
+        AlphaType::Premul,
+        None,
+    );
+    let row_bytes = info.min_row_bytes();
+    let mut pixels = vec![0u8; info.compute_byte_size(row_bytes)];
+    assert_eq!(
+        codec.get_pixels_with_options(&info, &mut pixels, row_bytes, None),
+        codec::Result::Success
+    );
+
+    let mut scanlines = vec![0u8; pixels.len()];
+    assert_eq!(
+        codec.start_scanline_decode(&info, None),
+        codec::Result::Success
+    );
+    let height = info.height() as usize;
+    assert_eq!(
+        codec.get_scanlines(&mut scanlines, height, row_bytes),
+        height
+    );
+    assert_eq!(scanlines, pixels);
+
+    let image = Image::from_encoded(Data::new_copy(bytes)).unwrap();
+    let mut image_pixels = vec![0u8; pixels.len()];
+    assert!(image.read_pixels(
+        &info,
+        &mut image_pixels,
+        row_bytes,
+        (0, 0),
+        skia_safe::image::CachingHint::Allow
+    ));
+    assert_eq!(image_pixels, pixels);
+}
+
+#[test]
+fn test_jpeg_exif_origin() {
+    use skia_safe::{EncodedOrigin, ISize};
+
+    let mut bitmap = Bitmap::new();
+    assert!(bitmap.try_alloc_n32_pixels((4, 2), true));
+    let jpeg = bitmap.encode(EncodedImageFormat::JPEG, 100).unwrap();
+
+    // An APP1 segment with a big endian EXIF block that only contains the orientation tag
+    // (0x0112) with the value 6 (rotated 90 degrees clockwise).
+    let exif: &[u8] = &[
+        0xff, 0xe1, 0, 34, b'E', b'x', b'i', b'f', 0, 0, b'M', b'M', 0, 42, 0, 0, 0, 8, 0, 1, 0x01,
+        0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0, 0, 0, 0, 0,
+    ];
+    let jpeg = jpeg.as_bytes();
+    let with_exif: Vec<u8> = [&jpeg[..2], exif, &jpeg[2..]].concat();
+
+    let codec = codec::Codec::from_data(Data::new_copy(jpeg)).unwrap();
+    assert_eq!(codec.origin(), EncodedOrigin::TopLeft);
+    assert_eq!(codec.dimensions_after_orientation(), ISize::new(4, 2));
+
+    let codec = codec::Codec::from_data(Data::new_copy(&with_exif)).unwrap();
+    assert_eq!(codec.origin(), EncodedOrigin::RightTop);
+    assert_eq!(codec.dimensions(), ISize::new(4, 2));
+    assert_eq!(codec.dimensions_after_orientation(), ISize::new(2, 4));
+}